@@ -0,0 +1,2 @@
+today = Today is {DATETIME($date, datestyle: "full")}
+meeting = {DATETIME($date, timeStyle: "shortt")}
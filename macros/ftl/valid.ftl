@@ -0,0 +1,2 @@
+today = Today is {DATETIME($date, dateStyle: "full")}
+meeting = {DATETIME($date, "Europe/Paris", timeStyle: "short")}
@@ -0,0 +1,69 @@
+//! Compile-time checks of the [`DATETIME`] calls of Fluent translations
+//!
+//! [`DATETIME`]: https://docs.rs/fluent-datetime/latest/fluent_datetime/fn.DATETIME.html
+
+use proc_macro::{TokenStream, TokenTree};
+
+use fluent_datetime::fluent_bundle::FluentResource;
+
+/// Checks an FTL file at build time, and expands to its contents
+///
+/// The path is relative to the crate root, like `CARGO_MANIFEST_DIR`.
+/// Compilation fails if the file doesn't parse, or if a `DATETIME` call
+/// has an unknown option or an invalid value, see
+/// [`validate_resource_strict`].  Since the file is included, editing it
+/// triggers a new check.
+///
+/// ```
+/// use fluent_datetime_macros::validate_ftl_datetime;
+///
+/// static EN: &str = validate_ftl_datetime!("ftl/valid.ftl");
+/// assert!(EN.starts_with("today = "));
+/// ```
+///
+/// ```compile_fail
+/// use fluent_datetime_macros::validate_ftl_datetime;
+///
+/// // today: DATETIME: unknown option datestyle
+/// // meeting: DATETIME: invalid value "shortt" for option timeStyle
+/// static EN: &str = validate_ftl_datetime!("ftl/invalid.ftl");
+/// ```
+///
+/// [`validate_resource_strict`]: https://docs.rs/fluent-datetime/latest/fluent_datetime/fn.validate_resource_strict.html
+#[proc_macro]
+pub fn validate_ftl_datetime(input: TokenStream) -> TokenStream {
+    match validate(input) {
+        Ok(path) => format!("include_str!({path:?})"),
+        Err(message) => format!("compile_error!({message:?})"),
+    }
+    .parse()
+    .expect("Valid tokens")
+}
+
+/// The absolute path of the file, once checked
+fn validate(input: TokenStream) -> Result<String, String> {
+    let mut tokens = input.into_iter();
+    let path = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal.to_string(),
+        _ => return Err("expected the path of an FTL file, as a string literal".to_string()),
+    };
+    let path = match path.strip_prefix('"').and_then(|p| p.strip_suffix('"')) {
+        Some(path) if !path.contains('\\') => path,
+        _ => return Err("expected a plain string literal, without escapes".to_string()),
+    };
+    let root = std::env::var("CARGO_MANIFEST_DIR").map_err(|e| e.to_string())?;
+    let path = std::path::Path::new(&root).join(path);
+    let path = path.to_str().ok_or("the path isn't valid UTF-8")?;
+    let source = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    let resource = FluentResource::try_new(source)
+        .map_err(|(_, errors)| format!("can't parse {path}: {errors:?}"))?;
+    let diagnostics = fluent_datetime::validate_resource_strict(&resource);
+    if diagnostics.is_empty() {
+        return Ok(path.to_string());
+    }
+    let mut message = format!("invalid DATETIME calls in {path}:");
+    for diagnostic in diagnostics {
+        message.push_str(&format!("\n  {diagnostic}"));
+    }
+    Err(message)
+}
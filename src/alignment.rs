@@ -0,0 +1,69 @@
+//! Output that lines up in table columns and log views
+
+use std::fmt;
+
+/// How formatted datetimes are laid out, see
+/// [`FluentDateTimeOptions::set_alignment`](crate::FluentDateTimeOptions::set_alignment)
+///
+/// Column alignment writes hours with two digits and replaces the
+/// no-break spaces of locale data with plain spaces, so times of the same
+/// locale and options have the same length in a fixed-width font.
+/// Datetimes with a time are then formatted from their fields rather than
+/// the locale's style patterns, which writes short dates with the full
+/// year.  Day periods keep their width: "AM" and "PM" match in English,
+/// but not in every language.
+///
+/// ```
+/// use fluent_datetime::{datetime, Alignment};
+/// use icu_datetime::options::length;
+///
+/// let mut datetime = datetime!(1989-11-09 09:05);
+/// datetime.options.set_time_style(Some(length::Time::Short));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "9:05\u{202f}AM");
+///
+/// datetime.options.set_alignment(Some(Alignment::Column));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "09:05 AM");
+/// datetime.options.set_date_style(Some(length::Date::Medium));
+/// assert_eq!(datetime.format(&"de-DE".parse()?).unwrap(), "9. Nov. 1989, 09:05");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Alignment {
+    /// As provided by locale data
+    #[default]
+    Natural,
+    /// Two-digit hours and plain spaces
+    Column,
+}
+
+impl Alignment {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "natural" => Self::Natural,
+            "column" => Self::Column,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn is_column(self) -> bool {
+        self == Self::Column
+    }
+}
+
+/// Replaces no-break spaces with plain ones on their way to the sink
+pub(crate) struct PlainSpaces<'a, W: ?Sized>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write + ?Sized> fmt::Write for PlainSpaces<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut parts = s.split(['\u{a0}', '\u{202f}']);
+        if let Some(first) = parts.next() {
+            self.0.write_str(first)?;
+        }
+        for part in parts {
+            self.0.write_char(' ')?;
+            self.0.write_str(part)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+//! Previews the messages of an FTL file across locales and sample datetimes
+//!
+//! ```text
+//! fluent-datetime messages.ftl --locales en-US,de-DE --date 1989-11-09T23:30
+//! ```
+//!
+//! Each sample datetime is passed as every variable of the file, except
+//! those given with `--arg name=value`.
+
+use std::collections::BTreeSet;
+use std::process::ExitCode;
+
+use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use fluent_datetime::fluent_syntax::ast;
+use fluent_datetime::{BundleExt, FluentDateTime, IntlSupport};
+use unic_langid::LanguageIdentifier;
+
+const USAGE: &str = "\
+usage: fluent-datetime FILE.ftl --locales LOCALE[,LOCALE...] --date DATETIME...
+                       [--arg NAME=VALUE...]
+
+DATETIME is ISO 8601, like 1989-11-09 or 1989-11-09T23:30:00; --date can
+be repeated.  Samples are passed as every variable of FILE.ftl, except
+those given with --arg; VALUE is a datetime, a number or a string.";
+
+struct Options {
+    path: String,
+    locales: Vec<LanguageIdentifier>,
+    /// The samples, as given and parsed
+    dates: Vec<(String, FluentDateTime)>,
+    args: Vec<(String, String)>,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut path = None;
+    let mut locales = vec![];
+    let mut dates = vec![];
+    let mut args = vec![];
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        let mut value = |flag: &str| argv.next().ok_or_else(|| format!("{flag} needs a value"));
+        match arg.as_str() {
+            "--locales" => {
+                for locale in value("--locales")?.split(',') {
+                    locales.push(
+                        locale
+                            .parse()
+                            .map_err(|_| format!("invalid locale {locale}"))?,
+                    );
+                }
+            }
+            "--date" => {
+                let date = value("--date")?;
+                let parsed = date.parse().map_err(|e| format!("{e}"))?;
+                dates.push((date, parsed));
+            }
+            "--arg" => {
+                let arg = value("--arg")?;
+                let (name, value) = arg
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected NAME=VALUE, not {arg}"))?;
+                args.push((name.to_string(), value.to_string()));
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}")),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+    let path = path.ok_or("missing FTL file")?;
+    if locales.is_empty() {
+        return Err("missing --locales".to_string());
+    }
+    if dates.is_empty() {
+        return Err("missing --date".to_string());
+    }
+    Ok(Options {
+        path,
+        locales,
+        dates,
+        args,
+    })
+}
+
+/// The variables referenced by a resource
+fn variables(resource: &FluentResource) -> BTreeSet<&str> {
+    fn pattern<'s>(pattern: &ast::Pattern<&'s str>, vars: &mut BTreeSet<&'s str>) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                expr(expression, vars);
+            }
+        }
+    }
+    fn expr<'s>(expression: &ast::Expression<&'s str>, vars: &mut BTreeSet<&'s str>) {
+        match expression {
+            ast::Expression::Inline(inline) => inline_expr(inline, vars),
+            ast::Expression::Select { selector, variants } => {
+                inline_expr(selector, vars);
+                for variant in variants {
+                    pattern(&variant.value, vars);
+                }
+            }
+        }
+    }
+    fn inline_expr<'s>(inline: &ast::InlineExpression<&'s str>, vars: &mut BTreeSet<&'s str>) {
+        match inline {
+            ast::InlineExpression::VariableReference { id } => {
+                vars.insert(id.name);
+            }
+            ast::InlineExpression::FunctionReference { arguments, .. }
+            | ast::InlineExpression::TermReference {
+                arguments: Some(arguments),
+                ..
+            } => {
+                for arg in &arguments.positional {
+                    inline_expr(arg, vars);
+                }
+                for arg in &arguments.named {
+                    inline_expr(&arg.value, vars);
+                }
+            }
+            ast::InlineExpression::Placeable { expression } => expr(expression, vars),
+            _ => {}
+        }
+    }
+
+    let mut vars = BTreeSet::new();
+    for entry in resource.entries() {
+        let (value, attributes) = match entry {
+            ast::Entry::Message(m) => (m.value.as_ref(), &m.attributes),
+            ast::Entry::Term(t) => (Some(&t.value), &t.attributes),
+            _ => continue,
+        };
+        value
+            .into_iter()
+            .for_each(|value| pattern(value, &mut vars));
+        for attribute in attributes {
+            pattern(&attribute.value, &mut vars);
+        }
+    }
+    vars
+}
+
+fn arg_value(value: &str) -> FluentValue<'static> {
+    if let Ok(datetime) = value.parse::<FluentDateTime>() {
+        datetime.into()
+    } else if let Ok(number) = value.parse::<f64>() {
+        number.into()
+    } else {
+        value.to_string().into()
+    }
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let source = std::fs::read_to_string(&options.path)
+        .map_err(|e| format!("can't read {}: {e}", options.path))?;
+    let resource = FluentResource::try_new(source)
+        .map_err(|(_, errors)| format!("can't parse {}: {errors:?}", options.path))?;
+    let variables = variables(&resource);
+    let messages: Vec<_> = resource
+        .entries()
+        .filter_map(|entry| match entry {
+            ast::Entry::Message(m) => Some((
+                m.id.name,
+                m.value.is_some(),
+                m.attributes.iter().map(|a| a.id.name).collect::<Vec<_>>(),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    for locale in &options.locales {
+        let mut bundle = FluentBundle::new(vec![locale.clone()]);
+        bundle.set_use_isolating(false);
+        bundle
+            .add_intl_support(IntlSupport::all())
+            .map_err(|e| e.to_string())?;
+        bundle
+            .add_resource(&resource)
+            .map_err(|errors| format!("can't add {}: {errors:?}", options.path))?;
+        for (sample, date) in &options.dates {
+            println!("# {locale}, {sample}");
+            let mut args = FluentArgs::new();
+            for &name in &variables {
+                args.set(name.to_string(), date.clone());
+            }
+            for (name, value) in &options.args {
+                args.set(name.clone(), arg_value(value));
+            }
+            for (id, has_value, attributes) in &messages {
+                let message = bundle.get_message(id).expect("Added above");
+                let mut errors = vec![];
+                if *has_value {
+                    let value = message.value().expect("Checked");
+                    let text = bundle.format_pattern_reporting(value, Some(&args), &mut errors);
+                    println!("{id} = {text}");
+                }
+                for attribute in attributes {
+                    let value = message.get_attribute(attribute).expect("Listed above");
+                    let text =
+                        bundle.format_pattern_reporting(value.value(), Some(&args), &mut errors);
+                    println!("{id}.{attribute} = {text}");
+                }
+                for error in errors {
+                    eprintln!("{id}: {error}");
+                }
+            }
+            println!();
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("fluent-datetime: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("fluent-datetime: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
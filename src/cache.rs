@@ -0,0 +1,323 @@
+//! Formatters shared between bundles
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::{provider, DateTimeFormatter, FluentDateTimeOptions, FormatterKey, LoadError};
+
+// With the data generation, see provider::generation
+type Key = (unic_langid::LanguageIdentifier, FormatterKey, u64);
+
+/// A formatter cache shared between bundles
+///
+/// Each Fluent bundle memoizes its own formatters, which doesn't help
+/// servers that build a bundle per request.  Register one cache on every
+/// bundle with [`DateTimeSupport::with_cache`](crate::DateTimeSupport::with_cache)
+/// so formatters are built once per locale and options; clones share the
+/// same cache.  Concurrent bundles, which otherwise share a small
+/// process-wide cache, benefit too.
+///
+/// The cache only serves datetimes passed through [`DATETIME`](crate::DATETIME).
+/// Fluent formats a bare `{$date}` without calling any function of the
+/// bundle, so that placeable can't reach the cache: it uses the bundle's
+/// own formatters, or the process-wide cache for concurrent bundles.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, DateTimeSupport, FluentDateTime, FormatterCache};
+/// use icu_calendar::DateTime;
+///
+/// let cache = FormatterCache::new();
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// for _request in 0..2 {
+///     let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+///     bundle.add_datetime_support_with(DateTimeSupport::new().with_cache(cache.clone()))?;
+///     bundle.add_resource(FluentResource::try_new(
+///         "today = {DATETIME($date, dateStyle: \"long\")}\nbare = {$date}".to_string(),
+///     ).unwrap()).unwrap();
+///     bundle.set_use_isolating(false);
+///     let args = FluentArgs::from_iter([("date", FluentDateTime::from(datetime.clone()))]);
+///     let mut errors = vec![];
+///     let mut format = |id| {
+///         let pattern = bundle.get_message(id).unwrap().value().unwrap();
+///         bundle.format_pattern(pattern, Some(&args), &mut errors).into_owned()
+///     };
+///     assert_eq!(format("today"), "November 9, 1989");
+///     assert_eq!(format("bare"), "11/9/89");
+/// }
+/// // The bare placeable built its formatter outside the cache
+/// assert_eq!(cache.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The cache grows with every locale and options combination seen, unless
+/// created with [`with_capacity`](Self::with_capacity).  Registering a data
+/// blob makes it build new formatters from the updated data.
+#[derive(Clone, Default)]
+pub struct FormatterCache {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    formatters: HashMap<Key, Entry>,
+    capacity: Option<usize>,
+    /// Counts lookups, to find the least recently used entry
+    clock: u64,
+    stats: CacheStats,
+}
+
+/// Counters of a [`FormatterCache`], see [`FormatterCache::stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// Lookups that found a formatter
+    pub hits: u64,
+    /// Lookups that had to build a formatter, successfully or not
+    pub misses: u64,
+    /// Formatters dropped to stay within capacity
+    pub evictions: u64,
+    /// Time spent building formatters, including locale data loading
+    pub build_time: Duration,
+}
+
+struct Entry {
+    formatter: Arc<DateTimeFormatter>,
+    last_used: u64,
+}
+
+impl State {
+    fn lookup(&mut self, key: &Key) -> Option<Arc<DateTimeFormatter>> {
+        self.clock += 1;
+        let entry = self.formatters.get_mut(key)?;
+        entry.last_used = self.clock;
+        Some(entry.formatter.clone())
+    }
+
+    fn insert(&mut self, key: Key, formatter: Arc<DateTimeFormatter>) -> Arc<DateTimeFormatter> {
+        if let Some(formatter) = self.lookup(&key) {
+            return formatter;
+        }
+        if let Some(capacity) = self.capacity {
+            if capacity == 0 {
+                return formatter;
+            }
+            self.evict(capacity - 1);
+        }
+        self.formatters.insert(
+            key,
+            Entry {
+                formatter: formatter.clone(),
+                last_used: self.clock,
+            },
+        );
+        formatter
+    }
+
+    /// Drops the least recently used entries down to `len`
+    fn evict(&mut self, len: usize) {
+        while self.formatters.len() > len {
+            let oldest = self
+                .formatters
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(key) = oldest {
+                self.formatters.remove(&key);
+                self.stats.evictions += 1;
+            }
+        }
+    }
+}
+
+impl FormatterCache {
+    /// An empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty cache holding at most `capacity` formatters
+    ///
+    /// Past that, the least recently used formatter is dropped.
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTime, FormatterCache};
+    /// # use fluent_datetime::{BundleExt, DateTimeSupport};
+    /// # use fluent_datetime::fluent_bundle::{FluentBundle, FluentResource};
+    /// # use fluent_datetime::fluent_bundle::FluentArgs;
+    /// # use icu_calendar::DateTime;
+    ///
+    /// let cache = FormatterCache::with_capacity(2);
+    /// # let datetime = FluentDateTime::from(DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap());
+    /// for lang in ["en-US", "fr", "de"] {
+    ///     let mut bundle = FluentBundle::new(vec![lang.parse()?]);
+    ///     bundle.add_datetime_support_with(DateTimeSupport::new().with_cache(cache.clone()))?;
+    ///     // ... format a datetime in each bundle
+    /// #   bundle.add_resource(FluentResource::try_new("d = {DATETIME($d)}".to_string()).unwrap()).unwrap();
+    /// #   bundle.format_pattern(bundle.get_message("d").unwrap().value().unwrap(),
+    /// #       Some(&FluentArgs::from_iter([("d", datetime.clone())])), &mut vec![]);
+    /// }
+    /// assert_eq!(cache.len(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let cache = Self::new();
+        cache.lock().capacity = Some(capacity);
+        cache
+    }
+
+    /// The maximum number of cached formatters, if bounded
+    pub fn capacity(&self) -> Option<usize> {
+        self.lock().capacity
+    }
+
+    /// Changes the maximum number of cached formatters
+    ///
+    /// Shrinking drops the least recently used formatters right away;
+    /// `None` removes the bound.
+    pub fn set_capacity(&self, capacity: Option<usize>) {
+        let mut state = self.lock();
+        state.capacity = capacity;
+        if let Some(capacity) = capacity {
+            state.evict(capacity);
+        }
+    }
+
+    /// Hit, miss and build time counters, to check that formatters are
+    /// actually reused
+    ///
+    /// ```
+    /// use fluent_datetime::FormatterCache;
+    /// # use fluent_datetime::{BundleExt, DateTimeSupport, FluentDateTime};
+    /// # use fluent_datetime::fluent_bundle::{FluentBundle, FluentResource};
+    /// # use fluent_datetime::fluent_bundle::FluentArgs;
+    /// # use icu_calendar::DateTime;
+    ///
+    /// let cache = FormatterCache::new();
+    /// # let datetime = FluentDateTime::from(DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap());
+    /// for _request in 0..3 {
+    ///     let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    ///     bundle.add_datetime_support_with(DateTimeSupport::new().with_cache(cache.clone()))?;
+    ///     // ... format a datetime
+    /// #   bundle.add_resource(FluentResource::try_new("d = {DATETIME($d)}".to_string()).unwrap()).unwrap();
+    /// #   bundle.format_pattern(bundle.get_message("d").unwrap().value().unwrap(),
+    /// #       Some(&FluentArgs::from_iter([("d", datetime.clone())])), &mut vec![]);
+    /// }
+    /// let stats = cache.stats();
+    /// assert_eq!((stats.hits, stats.misses), (2, 1));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    /// Resets the counters returned by [`stats`](Self::stats)
+    pub fn reset_stats(&self) {
+        self.lock().stats = CacheStats::default();
+    }
+
+    /// The number of cached formatters
+    ///
+    /// Options that only change how a datetime is written, like its UTC
+    /// offset, share a formatter:
+    ///
+    /// ```
+    /// # use fluent_datetime::{BundleExt, DateTimeSupport, FluentDateTime, FormatterCache};
+    /// # use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// # use icu_calendar::DateTime;
+    /// let cache = FormatterCache::new();
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.add_datetime_support_with(DateTimeSupport::new().with_cache(cache.clone()))?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "cet = {DATETIME($d, timeStyle: \"short\", utcOffset: \"+01:00\")}
+    /// jst = {DATETIME($d, timeStyle: \"short\", utcOffset: \"+09:00\")}".to_string(),
+    /// ).unwrap()).unwrap();
+    /// bundle.set_use_isolating(false);
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 22, 30, 0).unwrap();
+    /// let args = FluentArgs::from_iter([("d", FluentDateTime::from(datetime))]);
+    /// let mut format = |id| {
+    ///     let pattern = bundle.get_message(id).unwrap().value().unwrap();
+    ///     bundle.format_pattern(pattern, Some(&args), &mut vec![]).into_owned()
+    /// };
+    /// assert_eq!(format("cet"), "11:30\u{202f}PM");
+    /// assert_eq!(format("jst"), "7:30\u{202f}AM");
+    /// assert_eq!(cache.len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().formatters.len()
+    }
+
+    /// Whether no formatter is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops the cached formatters, for example after swapping locale data
+    pub fn clear(&self) {
+        self.lock().formatters.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The formatter for a locale and options, built if needed
+    pub(crate) fn get(
+        &self,
+        lang: &unic_langid::LanguageIdentifier,
+        options: &FluentDateTimeOptions,
+    ) -> Result<Arc<DateTimeFormatter>, LoadError> {
+        let formatter_key = options.formatter_key();
+        let key = (lang.clone(), formatter_key.clone(), provider::generation());
+        {
+            let mut state = self.lock();
+            if let Some(formatter) = state.lookup(&key) {
+                state.stats.hits += 1;
+                return Ok(formatter);
+            }
+            state.stats.misses += 1;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(locale = %lang, "formatter cache miss");
+        // Not holding the lock while loading data; racing threads build
+        // the same formatter and the first one is kept
+        let provider = formatter_key.provider().ok_or(LoadError::NoProvider)?;
+        let start = Instant::now();
+        let formatter = formatter_key.make_formatter_with_fallbacks(provider, lang);
+        let mut state = self.lock();
+        state.stats.build_time += start.elapsed();
+        Ok(state.insert(key, Arc::new(formatter?)))
+    }
+}
+
+impl PartialEq for FormatterCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for FormatterCache {}
+
+impl std::hash::Hash for FormatterCache {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.state).hash(state);
+    }
+}
+
+impl fmt::Debug for FormatterCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormatterCache")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// The cache of concurrent bundles that have none of their own
+pub(crate) fn shared() -> &'static FormatterCache {
+    static SHARED: OnceLock<FormatterCache> = OnceLock::new();
+    SHARED.get_or_init(|| FormatterCache::with_capacity(64))
+}
@@ -0,0 +1,351 @@
+//! Formatters for the output calendar of a locale
+//!
+//! ICU's [`DateTimeFormatter`](icu_datetime::DateTimeFormatter) handles
+//! any calendar, and so links the data of all of them.  These formatters
+//! only support the calendars whose cargo features are enabled.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use icu_calendar::{AnyCalendarKind, Calendar, CalendarError, DateTime, Gregorian, Iso, Ref};
+use icu_datetime::{
+    CldrCalendar, DateTimeFormatterOptions, TypedDateTimeFormatter, TypedZonedDateTimeFormatter,
+};
+use icu_locid::extensions::unicode::key;
+use icu_provider::{DataLocale, DataPayload, DataRequest};
+use icu_timezone::CustomTimeZone;
+use writeable::Writeable;
+
+use crate::lunisolar::LeapMonths;
+use crate::names::SymbolsMarker;
+use crate::provider::{load, load_data, Provider};
+use crate::{FluentDateTime, LoadError};
+
+/// Loads a calendar that needs data, such as Japanese eras
+#[allow(unused_macros)] // when no such calendar is enabled
+macro_rules! with_data {
+    ($cal:ty) => {
+        |provider: Provider| -> Result<$cal, CalendarError> {
+            Ok(match provider {
+                #[cfg(feature = "compiled_data")]
+                Provider::Compiled => <$cal>::new(),
+                Provider::Any(p) => <$cal>::try_new_with_any_provider(&p)?,
+                #[cfg(feature = "buffer_provider")]
+                Provider::Buffer(p) => <$cal>::try_new_with_buffer_provider(&p)?,
+            })
+        }
+    };
+}
+
+/// Builds a formatter for components options, like week numbers and
+/// eras, which ICU only supports in its experimental constructors
+macro_rules! experimental {
+    ($formatter:ty, $provider:expr, $locale:expr, $($args:expr),+) => {
+        match $provider {
+            #[cfg(feature = "compiled_data")]
+            Provider::Compiled => <$formatter>::try_new_experimental($locale, $($args),+)?,
+            Provider::Any(p) => {
+                use icu_provider::AsDowncastingAnyProvider;
+                <$formatter>::try_new_experimental_unstable(&p.as_downcasting(), $locale, $($args),+)?
+            }
+            #[cfg(feature = "buffer_provider")]
+            Provider::Buffer(p) => {
+                use icu_provider::AsDeserializingBufferProvider;
+                <$formatter>::try_new_experimental_unstable(
+                    &p.as_deserializing(),
+                    $locale,
+                    $($args),+
+                )?
+            }
+        }
+    };
+}
+
+macro_rules! calendars {
+    ($($(#[$attr:meta])* $variant:ident($cal:ty) = $kind:pat => $new:expr,)*) => {
+        /// A datetime formatter and its calendar
+        pub(crate) enum PlainFormatter {
+            $($(#[$attr])* $variant(Box<TypedDateTimeFormatter<$cal>>, $cal),)*
+        }
+
+        /// A zoned datetime formatter and its calendar
+        pub(crate) enum ZonedFormatter {
+            $($(#[$attr])* $variant(Box<TypedZonedDateTimeFormatter<$cal>>, $cal),)*
+        }
+
+        pub(crate) fn is_enabled(kind: AnyCalendarKind) -> bool {
+            match kind {
+                $($(#[$attr])* $kind => true,)*
+                _ => false,
+            }
+        }
+
+        /// Loads the month and weekday names of the locale's calendar
+        pub(crate) fn load_symbols(
+            provider: Provider,
+            locale: &DataLocale,
+        ) -> Result<DataPayload<SymbolsMarker>, LoadError> {
+            let (kind, locale) = resolve(locale)?;
+            let req = DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            };
+            match kind {
+                $($(#[$attr])* $kind => Ok(load_data!(
+                    provider,
+                    <$cal as CldrCalendar>::DateSymbolsV1Marker,
+                    req
+                )?
+                .take_payload()?
+                .cast()),)*
+                _ => Err(LoadError::UnsupportedCalendar),
+            }
+        }
+
+        impl PlainFormatter {
+            pub(crate) fn try_new(
+                provider: Provider,
+                locale: &DataLocale,
+                options: DateTimeFormatterOptions,
+            ) -> Result<Self, LoadError> {
+                let (kind, locale) = resolve(locale)?;
+                match kind {
+                    $($(#[$attr])* $kind => Ok(Self::$variant(
+                        Box::new(match options {
+                            DateTimeFormatterOptions::Components(_) => {
+                                experimental!(TypedDateTimeFormatter::<$cal>, provider, &locale, options)
+                            }
+                            _ => load!(
+                                provider,
+                                TypedDateTimeFormatter::<$cal>::try_new,
+                                TypedDateTimeFormatter::<$cal>::try_new_with_any_provider,
+                                TypedDateTimeFormatter::<$cal>::try_new_with_buffer_provider,
+                                &locale,
+                                options,
+                            )?,
+                        }),
+                        ($new)(provider)?,
+                    )),)*
+                    _ => Err(LoadError::UnsupportedCalendar),
+                }
+            }
+
+            pub(crate) fn write_to<W: fmt::Write + ?Sized>(
+                &self,
+                value: DateTime<Iso>,
+                leap_months: Option<&LeapMonths>,
+                sink: &mut W,
+            ) -> fmt::Result {
+                match self {
+                    $($(#[$attr])* Self::$variant(dtf, cal) => {
+                        if !supported_years(cal.any_calendar_kind()).contains(&value.date.year().number) {
+                            return Err(fmt::Error);
+                        }
+                        let value = DateTime::new_from_iso(value, Ref(cal));
+                        match leap_months {
+                            Some(leap_months) => leap_months.write_to(
+                                &value,
+                                |input| dtf.format(input).write_to_string().into_owned(),
+                                sink,
+                            ),
+                            None => dtf.format(&value).write_to(sink),
+                        }
+                    })*
+                }
+            }
+        }
+
+        impl ZonedFormatter {
+            pub(crate) fn try_new(
+                provider: Provider,
+                locale: &DataLocale,
+                options: DateTimeFormatterOptions,
+            ) -> Result<Self, LoadError> {
+                let (kind, locale) = resolve(locale)?;
+                match kind {
+                    $($(#[$attr])* $kind => Ok(Self::$variant(
+                        Box::new(match options {
+                            DateTimeFormatterOptions::Components(_) => experimental!(
+                                TypedZonedDateTimeFormatter::<$cal>,
+                                provider,
+                                &locale,
+                                options,
+                                Default::default()
+                            ),
+                            _ => load!(
+                                provider,
+                                TypedZonedDateTimeFormatter::<$cal>::try_new,
+                                TypedZonedDateTimeFormatter::<$cal>::try_new_with_any_provider,
+                                TypedZonedDateTimeFormatter::<$cal>::try_new_with_buffer_provider,
+                                &locale,
+                                options,
+                                Default::default(),
+                            )?,
+                        }),
+                        ($new)(provider)?,
+                    )),)*
+                    _ => Err(LoadError::UnsupportedCalendar),
+                }
+            }
+
+            pub(crate) fn write_to<W: fmt::Write + ?Sized>(
+                &self,
+                value: DateTime<Iso>,
+                time_zone: &CustomTimeZone,
+                leap_months: Option<&LeapMonths>,
+                sink: &mut W,
+            ) -> fmt::Result {
+                match self {
+                    $($(#[$attr])* Self::$variant(dtf, cal) => {
+                        if !supported_years(cal.any_calendar_kind()).contains(&value.date.year().number) {
+                            return Err(fmt::Error);
+                        }
+                        let value = DateTime::new_from_iso(value, Ref(cal));
+                        match leap_months {
+                            Some(leap_months) => leap_months.write_to(
+                                &value,
+                                |input| dtf.format(input, time_zone).write_to_string().into_owned(),
+                                sink,
+                            ),
+                            None => dtf.format(&value, time_zone).write_to(sink),
+                        }
+                    })*
+                }
+            }
+        }
+    };
+}
+
+calendars! {
+    Gregorian(Gregorian) = AnyCalendarKind::Gregorian | AnyCalendarKind::Iso => |_| {
+        Ok::<_, CalendarError>(Gregorian)
+    },
+    #[cfg(feature = "calendar_buddhist")]
+    Buddhist(icu_calendar::buddhist::Buddhist) = AnyCalendarKind::Buddhist => |_| {
+        Ok::<_, CalendarError>(icu_calendar::buddhist::Buddhist)
+    },
+    #[cfg(feature = "calendar_chinese")]
+    Chinese(icu_calendar::chinese::Chinese) = AnyCalendarKind::Chinese =>
+        with_data!(icu_calendar::chinese::Chinese),
+    #[cfg(feature = "calendar_coptic")]
+    Coptic(icu_calendar::coptic::Coptic) = AnyCalendarKind::Coptic => |_| {
+        Ok::<_, CalendarError>(icu_calendar::coptic::Coptic)
+    },
+    #[cfg(feature = "calendar_dangi")]
+    Dangi(icu_calendar::dangi::Dangi) = AnyCalendarKind::Dangi =>
+        with_data!(icu_calendar::dangi::Dangi),
+    #[cfg(feature = "calendar_ethiopian")]
+    Ethiopian(icu_calendar::ethiopian::Ethiopian) = AnyCalendarKind::Ethiopian => |_| {
+        Ok::<_, CalendarError>(icu_calendar::ethiopian::Ethiopian::new())
+    },
+    #[cfg(feature = "calendar_ethiopian")]
+    EthiopianAmeteAlem(icu_calendar::ethiopian::Ethiopian) =
+        AnyCalendarKind::EthiopianAmeteAlem => |_| {
+        Ok::<_, CalendarError>(icu_calendar::ethiopian::Ethiopian::new_with_era_style(
+            icu_calendar::ethiopian::EthiopianEraStyle::AmeteAlem,
+        ))
+    },
+    #[cfg(feature = "calendar_hebrew")]
+    Hebrew(icu_calendar::hebrew::Hebrew) = AnyCalendarKind::Hebrew => |_| {
+        Ok::<_, CalendarError>(icu_calendar::hebrew::Hebrew::new())
+    },
+    #[cfg(feature = "calendar_indian")]
+    Indian(icu_calendar::indian::Indian) = AnyCalendarKind::Indian => |_| {
+        Ok::<_, CalendarError>(icu_calendar::indian::Indian::new())
+    },
+    #[cfg(feature = "calendar_islamic")]
+    IslamicCivil(icu_calendar::islamic::IslamicCivil) = AnyCalendarKind::IslamicCivil => |_| {
+        Ok::<_, CalendarError>(icu_calendar::islamic::IslamicCivil::new())
+    },
+    #[cfg(feature = "calendar_islamic")]
+    IslamicObservational(icu_calendar::islamic::IslamicObservational) =
+        AnyCalendarKind::IslamicObservational =>
+        with_data!(icu_calendar::islamic::IslamicObservational),
+    #[cfg(feature = "calendar_islamic")]
+    IslamicTabular(icu_calendar::islamic::IslamicTabular) =
+        AnyCalendarKind::IslamicTabular => |_| {
+        Ok::<_, CalendarError>(icu_calendar::islamic::IslamicTabular::new())
+    },
+    #[cfg(feature = "calendar_islamic")]
+    IslamicUmmAlQura(icu_calendar::islamic::IslamicUmmAlQura) =
+        AnyCalendarKind::IslamicUmmAlQura =>
+        with_data!(icu_calendar::islamic::IslamicUmmAlQura),
+    #[cfg(feature = "calendar_japanese")]
+    Japanese(icu_calendar::japanese::Japanese) = AnyCalendarKind::Japanese =>
+        with_data!(icu_calendar::japanese::Japanese),
+    #[cfg(feature = "calendar_japanese")]
+    JapaneseExtended(icu_calendar::japanese::JapaneseExtended) =
+        AnyCalendarKind::JapaneseExtended =>
+        with_data!(icu_calendar::japanese::JapaneseExtended),
+    #[cfg(feature = "calendar_persian")]
+    Persian(icu_calendar::persian::Persian) = AnyCalendarKind::Persian => |_| {
+        Ok::<_, CalendarError>(icu_calendar::persian::Persian::new())
+    },
+    #[cfg(feature = "calendar_roc")]
+    Roc(icu_calendar::roc::Roc) = AnyCalendarKind::Roc => |_| {
+        Ok::<_, CalendarError>(icu_calendar::roc::Roc)
+    },
+}
+
+/// The ISO years a calendar formats
+///
+/// ICU computes the Chinese, Korean and observational Islamic calendars
+/// from astronomical models that break down far from the present, and
+/// panics or makes up months beyond ten thousand years or so.
+fn supported_years(kind: Option<AnyCalendarKind>) -> RangeInclusive<i32> {
+    match kind {
+        Some(
+            AnyCalendarKind::Chinese
+            | AnyCalendarKind::Dangi
+            | AnyCalendarKind::IslamicObservational
+            | AnyCalendarKind::IslamicUmmAlQura,
+        ) => -9999..=9999,
+        _ => FluentDateTime::YEARS,
+    }
+}
+
+/// Picks the output calendar of a locale
+///
+/// This is the `-u-ca` keyword if set, else the locale's default calendar
+/// (Buddhist in Thailand, Persian in Iran and Afghanistan, Gregorian
+/// elsewhere), falling back to Gregorian if that calendar isn't enabled.
+/// The keyword is removed
+/// from the returned locale: the typed formatters reject keywords they
+/// don't expect, such as `iso8601` for Gregorian.
+pub(crate) fn resolve(locale: &DataLocale) -> Result<(AnyCalendarKind, DataLocale), LoadError> {
+    let mut locale = locale.clone();
+    let kind = match locale.remove_unicode_ext(&key!("ca")) {
+        Some(ca) => {
+            AnyCalendarKind::get_for_bcp47_value(&ca).ok_or(LoadError::UnsupportedCalendar)?
+        }
+        None => Some(preferred(&locale))
+            .filter(|&kind| is_enabled(kind))
+            .unwrap_or(AnyCalendarKind::Gregorian),
+    };
+    Ok((kind, locale))
+}
+
+/// Every calendar [`preferred`] returns, for datagen
+pub(crate) const PREFERRED: [AnyCalendarKind; 3] = [
+    AnyCalendarKind::Gregorian,
+    AnyCalendarKind::Buddhist,
+    AnyCalendarKind::Persian,
+];
+
+/// The calendar a locale prefers, from the CLDR calendar preferences of
+/// its region, or of its language's likely region; keep [`PREFERRED`] in
+/// sync
+fn preferred(locale: &DataLocale) -> AnyCalendarKind {
+    use icu_locid::subtags::{language, region};
+
+    let region = locale.region();
+    if region == Some(region!("TH")) || region.is_none() && locale.language() == language!("th") {
+        AnyCalendarKind::Buddhist
+    } else if matches!(region, Some(r) if r == region!("IR") || r == region!("AF"))
+        || region.is_none() && locale.language() == language!("fa")
+    {
+        AnyCalendarKind::Persian
+    } else {
+        AnyCalendarKind::Gregorian
+    }
+}
@@ -0,0 +1,56 @@
+//! Contextual capitalization of formatted datetimes
+
+use icu_casemap::titlecase::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
+use icu_casemap::{CaseMapper, TitlecaseMapper};
+
+/// Where a formatted datetime appears, which determines its capitalization
+///
+/// CLDR patterns and names are in the form used in the middle of a
+/// sentence: many languages write month and weekday names in lowercase
+/// ("torsdag den 9. november 1989" in Danish).  When the datetime starts
+/// a sentence or stands alone, as in a UI label or list, the first word
+/// is capitalized instead ("Torsdag den 9. november 1989").
+///
+/// ```
+/// use fluent_datetime::{Casing, FluentDateTime};
+/// use icu_calendar::DateTime;
+/// use icu_datetime::options::length;
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut datetime = FluentDateTime::from(datetime);
+/// datetime.options.set_date_style(Some(length::Date::Full));
+/// assert_eq!(datetime.format(&"da".parse()?).unwrap(), "torsdag den 9. november 1989");
+///
+/// datetime.options.set_casing(Some(Casing::Standalone));
+/// assert_eq!(datetime.format(&"da".parse()?).unwrap(), "Torsdag den 9. november 1989");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Casing {
+    /// In running text; the form provided by locale data
+    #[default]
+    MiddleOfSentence,
+    /// At the start of a sentence
+    BeginningOfSentence,
+    /// On its own, for example in a UI label, menu or list
+    Standalone,
+}
+
+impl Casing {
+    pub(crate) fn needs_titlecase(self) -> bool {
+        self != Self::MiddleOfSentence
+    }
+}
+
+/// Capitalizes the first word of `formatted`
+pub(crate) fn titlecase(
+    mapper: &TitlecaseMapper<CaseMapper>,
+    formatted: &str,
+    langid: &icu_locid::LanguageIdentifier,
+) -> String {
+    let mut options = TitlecaseOptions::default();
+    options.leading_adjustment = LeadingAdjustment::Auto;
+    options.trailing_case = TrailingCase::Unchanged;
+    mapper.titlecase_segment_to_string(formatted, langid, options)
+}
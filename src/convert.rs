@@ -0,0 +1,554 @@
+//! Conversions between other datetime representations and [`FluentDateTime`]
+
+use std::str::FromStr;
+
+use fluent_bundle::FluentValue;
+use icu_calendar::{Date, DateTime, Gregorian, Iso, Time};
+
+use crate::{FluentDateTime, FluentDateTimeError, TimeZone};
+
+/// Anything that can be passed to Fluent as a datetime
+///
+/// This is implemented for ICU datetimes, for [`std::time::SystemTime`],
+/// for Unix timestamps in seconds (`i64`), and for the datetime types of
+/// [chrono](https://lib.rs/crates/chrono), [time](https://lib.rs/crates/time)
+/// and [jiff](https://lib.rs/crates/jiff) when the corresponding cargo
+/// features are enabled.
+///
+/// Values carrying a time zone or UTC offset are converted to their local
+/// wall-clock time.  Instants without one (timestamps, `SystemTime`) are
+/// rendered in UTC.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentValue};
+/// use fluent_datetime::IntoFluentDateTime;
+///
+/// fn greeting_args(date: impl IntoFluentDateTime) -> FluentArgs<'static> {
+///     FluentArgs::from_iter([("date", date.into_fluent_value())])
+/// }
+///
+/// let args = greeting_args(std::time::SystemTime::now());
+/// assert!(matches!(args.get("date"), Some(FluentValue::Custom(_))));
+///
+/// let date = 626_657_400_i64.into_fluent_datetime().unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (1989, 11, 9));
+/// assert_eq!((date.hour(), date.minute()), (23, 30));
+/// ```
+pub trait IntoFluentDateTime {
+    /// Converts to a [`FluentDateTime`]
+    ///
+    /// Returns `None` if the value is outside of the supported range.
+    fn into_fluent_datetime(self) -> Option<FluentDateTime>;
+
+    /// Converts to a [`FluentValue`], for use as a Fluent argument
+    ///
+    /// Values outside of the supported range become [`FluentValue::Error`].
+    fn into_fluent_value(self) -> FluentValue<'static>
+    where
+        Self: Sized,
+    {
+        match self.into_fluent_datetime() {
+            Some(dt) => dt.into(),
+            None => FluentValue::Error,
+        }
+    }
+}
+
+/// The datetime in a [`FluentValue`], if it holds one
+///
+/// For custom Fluent functions taking datetime arguments.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentValue};
+/// use fluent_datetime::{as_datetime, FluentDateTime};
+/// use icu_calendar::DateTime;
+///
+/// fn year<'a>(positional: &[FluentValue<'a>], _named: &FluentArgs) -> FluentValue<'a> {
+///     match positional.first().and_then(as_datetime) {
+///         Some(datetime) => datetime.year().into(),
+///         None => FluentValue::Error,
+///     }
+/// }
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let value = FluentValue::from(FluentDateTime::from(datetime));
+/// assert_eq!(year(&[value], &FluentArgs::new()), FluentValue::from(1989));
+/// assert!(matches!(year(&["1989".into()], &FluentArgs::new()), FluentValue::Error));
+///
+/// let value = FluentValue::from(FluentDateTime::from(datetime));
+/// let datetime: &FluentDateTime = (&value).try_into()?;
+/// assert_eq!(datetime.month(), 11);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn as_datetime<'v>(value: &'v FluentValue) -> Option<&'v FluentDateTime> {
+    match value {
+        FluentValue::Custom(custom) => custom.as_any().downcast_ref(),
+        _ => None,
+    }
+}
+
+impl<'v> TryFrom<&'v FluentValue<'_>> for &'v FluentDateTime {
+    type Error = FluentDateTimeError;
+
+    fn try_from(value: &'v FluentValue<'_>) -> Result<Self, Self::Error> {
+        as_datetime(value).ok_or(FluentDateTimeError::MissingDateTime)
+    }
+}
+
+impl IntoFluentDateTime for FluentDateTime {
+    fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+        Some(self)
+    }
+}
+
+impl IntoFluentDateTime for DateTime<Gregorian> {
+    fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+        let datetime = FluentDateTime::from(self);
+        FluentDateTime::YEARS
+            .contains(&datetime.year())
+            .then_some(datetime)
+    }
+}
+
+impl IntoFluentDateTime for DateTime<Iso> {
+    fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+        let datetime = FluentDateTime::from(self);
+        FluentDateTime::YEARS
+            .contains(&datetime.year())
+            .then_some(datetime)
+    }
+}
+
+impl IntoFluentDateTime for std::time::SystemTime {
+    fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+        let (secs, nanos) = match self.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => (i64::try_from(d.as_secs()).ok()?, d.subsec_nanos()),
+            Err(e) => {
+                // Before the epoch, round towards negative infinity
+                let d = e.duration();
+                let secs = i64::try_from(d.as_secs()).ok()?;
+                match d.subsec_nanos() {
+                    0 => (-secs, 0),
+                    n => (-secs - 1, 1_000_000_000 - n),
+                }
+            }
+        };
+        from_unix(secs, nanos)
+    }
+}
+
+/// Seconds since the Unix epoch, rendered in UTC
+impl IntoFluentDateTime for i64 {
+    fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+        from_unix(self, 0)
+    }
+}
+
+/// Parses ISO 8601, as `1989-11-09`, `1989-11-09T23:30` or
+/// `1989-11-09T23:30:00.5+01:00`
+///
+/// Like other values carrying a UTC offset, the datetime keeps its local
+/// wall-clock time; a space can stand in for the `T`.  Years outside of
+/// 0000 to 9999 take a sign, as in `-0043-03-15` (44 BCE, as ISO 8601
+/// counts a year zero) or `+12345-01-01`, up to [`FluentDateTime::YEARS`].
+///
+/// ```
+/// use fluent_datetime::{FluentDateTime, FluentDateTimeError};
+///
+/// let datetime: FluentDateTime = "1989-11-09T23:30:00+01:00".parse()?;
+/// assert_eq!((datetime.day(), datetime.hour()), (9, 23));
+/// assert!("1989-11-31".parse::<FluentDateTime>().is_err());
+///
+/// assert_eq!("-0043-03-15".parse::<FluentDateTime>()?.year(), -43);
+/// assert_eq!("+12345-01-01".parse::<FluentDateTime>()?.year(), 12345);
+/// assert_eq!(
+///     "+1000000-01-01".parse::<FluentDateTime>().unwrap_err(),
+///     FluentDateTimeError::YearOutOfRange(1_000_000)
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl FromStr for FluentDateTime {
+    type Err = FluentDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_iso8601(s)
+            .map(|(datetime, _)| datetime)
+            .ok_or_else(|| parse_error(s))
+    }
+}
+
+impl FluentDateTime {
+    /// Parses RFC 3339, as `1989-11-09T23:30:00+01:00`, keeping the UTC
+    /// offset, see [`with_utc_offset`](Self::with_utc_offset)
+    ///
+    /// ```
+    /// use fluent_datetime::FluentDateTime;
+    ///
+    /// let datetime = FluentDateTime::parse_rfc3339("1989-11-09T23:30:00+01:00")?;
+    /// assert_eq!(datetime.options.time_zone().unwrap().iana_id(), "+01:00");
+    /// assert!(FluentDateTime::parse_rfc3339("1989-11-09T23:30:00").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_rfc3339(s: &str) -> Result<Self, FluentDateTimeError> {
+        parse_iso8601(s)
+            .and_then(|(datetime, offset)| datetime.with_utc_offset(offset?))
+            .ok_or_else(|| parse_error(s))
+    }
+}
+
+/// The value of `s` if it is `len` ASCII digits
+fn digits(s: &str, len: usize) -> Option<u32> {
+    if s.len() == len && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// An ISO 8601 year, of four digits or more, with a sign if it has more
+/// or is negative
+fn parse_year(year: &str) -> Option<i32> {
+    let (negative, unsigned) = match year.strip_prefix(['-', '+']) {
+        Some(unsigned) => (year.starts_with('-'), unsigned),
+        None => (false, year),
+    };
+    if unsigned.len() < 4 || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let year: i32 = unsigned.parse().ok()?;
+    Some(if negative { -year } else { year })
+}
+
+/// Why `s` isn't an ISO 8601 datetime
+fn parse_error(s: &str) -> FluentDateTimeError {
+    let date = s.split(['T', ' ']).next().unwrap_or(s);
+    match date.rsplitn(3, '-').nth(2).and_then(parse_year) {
+        Some(year) if !FluentDateTime::YEARS.contains(&year) => {
+            FluentDateTimeError::YearOutOfRange(year)
+        }
+        _ => FluentDateTimeError::InvalidDateTime(s.to_string()),
+    }
+}
+
+/// The datetime, and its UTC offset in seconds if it has one
+fn parse_iso8601(s: &str) -> Option<(FluentDateTime, Option<i32>)> {
+    let (date, time) = match s.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    let mut fields = date.rsplitn(3, '-');
+    let day = digits(fields.next()?, 2)?;
+    let month = digits(fields.next()?, 2)?;
+    let year = parse_year(fields.next()?)?;
+    let (mut hour, mut minute, mut second, mut nanosecond) = (0, 0, 0, 0);
+    let mut offset = None;
+    if let Some(time) = time {
+        let time = match time.strip_suffix('Z') {
+            Some(time) => {
+                offset = Some(0);
+                time
+            }
+            None => match time.rfind(['+', '-']) {
+                Some(i) => {
+                    offset = Some(TimeZone::parse_offset(&time[i..])?);
+                    &time[..i]
+                }
+                None => time,
+            },
+        };
+        let (time, fraction) = match time.split_once(['.', ',']) {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+        let mut fields = time.split(':');
+        hour = digits(fields.next()?, 2)?;
+        minute = digits(fields.next()?, 2)?;
+        if let Some(field) = fields.next() {
+            second = digits(field, 2)?;
+        }
+        if fields.next().is_some() {
+            return None;
+        }
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || fraction.len() > 9 {
+                return None;
+            }
+            nanosecond = digits(fraction, fraction.len())? * 10_u32.pow(9 - fraction.len() as u32);
+        }
+    }
+    let datetime = from_fields(
+        year,
+        u8::try_from(month).ok()?,
+        u8::try_from(day).ok()?,
+        u8::try_from(hour).ok()?,
+        u8::try_from(minute).ok()?,
+        u8::try_from(second).ok()?,
+        nanosecond,
+    )?;
+    Some((datetime, offset))
+}
+
+/// Builds a datetime from proleptic Gregorian fields, within
+/// [`FluentDateTime::YEARS`]
+pub(crate) fn from_fields(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+) -> Option<FluentDateTime> {
+    if !FluentDateTime::YEARS.contains(&year) {
+        return None;
+    }
+    let date = Date::try_new_iso_date(year, month, day).ok()?;
+    let time = Time::try_new(hour, minute, second, nanosecond).ok()?;
+    Some(DateTime::new(date, time).into())
+}
+
+/// Builds a UTC datetime from a Unix timestamp
+pub(crate) fn from_unix(secs: i64, nanosecond: u32) -> Option<FluentDateTime> {
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days)?;
+    from_fields(
+        year,
+        month,
+        day,
+        (secs_of_day / 3600) as u8,
+        (secs_of_day / 60 % 60) as u8,
+        (secs_of_day % 60) as u8,
+        nanosecond,
+    )
+}
+
+/// Seconds since the Unix epoch, ignoring the fractional part
+pub(crate) fn to_unix(value: &DateTime<Gregorian>) -> i64 {
+    let iso = value.to_iso();
+    let days = days_from_civil(
+        iso.date.year().number,
+        iso.date.month().ordinal,
+        iso.date.day_of_month().0,
+    );
+    days * 86_400
+        + i64::from(iso.time.hour.number()) * 3600
+        + i64::from(iso.time.minute.number()) * 60
+        + i64::from(iso.time.second.number())
+}
+
+/// Shifts a datetime by a number of seconds
+pub(crate) fn shift_seconds(value: &DateTime<Gregorian>, secs: i64) -> Option<DateTime<Gregorian>> {
+    let shifted = from_unix(
+        to_unix(value).checked_add(secs)?,
+        value.time.nanosecond.number(),
+    )?;
+    Some(shifted.into_inner())
+}
+
+/// Shifts a datetime by a number of months, keeping the day of the month
+/// unless the new month is shorter, as in January 31st plus one month
+pub(crate) fn add_months(value: &DateTime<Gregorian>, months: i32) -> Option<DateTime<Gregorian>> {
+    let iso = value.to_iso();
+    let index = i64::from(iso.date.year().number) * 12
+        + i64::from(iso.date.month().ordinal - 1)
+        + i64::from(months);
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = index.rem_euclid(12) as u8 + 1;
+    let days_in_month = Date::try_new_iso_date(year, month, 1).ok()?.days_in_month();
+    let day = (iso.date.day_of_month().0 as u8).min(days_in_month);
+    let date = Date::try_new_iso_date(year, month, day).ok()?;
+    Some(DateTime::new(date, iso.time).to_calendar(Gregorian))
+}
+
+/// Converts a proleptic Gregorian date to days since 1970-01-01
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days since 1970-01-01 to a proleptic Gregorian date
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub(crate) fn civil_from_days(days: i64) -> Option<(i32, u8, u8)> {
+    let z = days.checked_add(719_468)?;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    Some((i32::try_from(year).ok()?, month, day))
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    impl IntoFluentDateTime for chrono::NaiveDateTime {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            from_fields(
+                self.year(),
+                self.month() as u8,
+                self.day() as u8,
+                self.hour() as u8,
+                self.minute() as u8,
+                // chrono represents leap seconds as nanoseconds overflow
+                (self.second() + self.nanosecond() / 1_000_000_000) as u8,
+                self.nanosecond() % 1_000_000_000,
+            )
+        }
+    }
+
+    impl IntoFluentDateTime for chrono::NaiveDate {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            self.and_time(chrono::NaiveTime::MIN).into_fluent_datetime()
+        }
+    }
+
+    impl<Tz: chrono::TimeZone> IntoFluentDateTime for chrono::DateTime<Tz> {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            self.naive_local().into_fluent_datetime()
+        }
+    }
+
+    impl FluentDateTime {
+        /// Converts a chrono datetime, keeping its UTC offset, see
+        /// [`with_utc_offset`](Self::with_utc_offset)
+        ///
+        /// ```
+        /// use fluent_datetime::FluentDateTime;
+        ///
+        /// let datetime = chrono::DateTime::parse_from_rfc3339("1989-11-09T23:30:00+02:00").unwrap();
+        /// let datetime = FluentDateTime::from_chrono_with_offset(&datetime).unwrap();
+        /// assert_eq!(datetime.options.time_zone().unwrap().fixed_offset(), Some(7200));
+        /// ```
+        pub fn from_chrono_with_offset<Tz: chrono::TimeZone>(
+            datetime: &chrono::DateTime<Tz>,
+        ) -> Option<Self> {
+            use chrono::Offset;
+
+            let offset = datetime.offset().fix().local_minus_utc();
+            datetime
+                .naive_local()
+                .into_fluent_datetime()?
+                .with_utc_offset(offset)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::*;
+
+    impl IntoFluentDateTime for time::PrimitiveDateTime {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            from_fields(
+                self.year(),
+                self.month().into(),
+                self.day(),
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.nanosecond(),
+            )
+        }
+    }
+
+    impl IntoFluentDateTime for time::Date {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            time::PrimitiveDateTime::new(self, time::Time::MIDNIGHT).into_fluent_datetime()
+        }
+    }
+
+    impl IntoFluentDateTime for time::OffsetDateTime {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            time::PrimitiveDateTime::new(self.date(), self.time()).into_fluent_datetime()
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_impls {
+    use super::*;
+
+    impl IntoFluentDateTime for jiff::civil::DateTime {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            from_fields(
+                self.year().into(),
+                self.month() as u8,
+                self.day() as u8,
+                self.hour() as u8,
+                self.minute() as u8,
+                self.second() as u8,
+                self.subsec_nanosecond() as u32,
+            )
+        }
+    }
+
+    impl IntoFluentDateTime for jiff::civil::Date {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            self.to_datetime(jiff::civil::Time::midnight())
+                .into_fluent_datetime()
+        }
+    }
+
+    impl IntoFluentDateTime for jiff::Timestamp {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            jiff::tz::Offset::UTC
+                .to_datetime(self)
+                .into_fluent_datetime()
+        }
+    }
+
+    impl IntoFluentDateTime for jiff::Zoned {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            self.datetime().into_fluent_datetime()
+        }
+    }
+
+    impl IntoFluentDateTime for &jiff::Zoned {
+        fn into_fluent_datetime(self) -> Option<FluentDateTime> {
+            self.datetime().into_fluent_datetime()
+        }
+    }
+
+    #[cfg(feature = "tzdb")]
+    impl FluentDateTime {
+        /// Converts a jiff zoned datetime, keeping its time zone, or its UTC
+        /// offset for zones without an IANA name
+        ///
+        /// ```
+        /// use fluent_datetime::FluentDateTime;
+        ///
+        /// let zoned: jiff::Zoned = "1989-11-09T23:30[Europe/Berlin]".parse().unwrap();
+        /// let datetime = FluentDateTime::from_zoned(&zoned).unwrap();
+        /// assert_eq!(datetime.options.time_zone().unwrap().iana_id(), "Europe/Berlin");
+        /// assert_eq!(datetime.hour(), 22);
+        /// ```
+        pub fn from_zoned(zoned: &jiff::Zoned) -> Option<Self> {
+            let utc = zoned.timestamp().into_fluent_datetime()?;
+            match zoned
+                .time_zone()
+                .iana_name()
+                .and_then(crate::TimeZone::from_iana)
+            {
+                Some(time_zone) => Some(utc.map_options(|o| o.set_time_zone(Some(time_zone)))),
+                None => zoned
+                    .datetime()
+                    .into_fluent_datetime()?
+                    .with_utc_offset(zoned.offset().seconds()),
+            }
+        }
+    }
+}
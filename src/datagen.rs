@@ -0,0 +1,280 @@
+//! The ICU data keys formatting needs, for generating trimmed data blobs
+//!
+//! Pass the keys to `icu4x-datagen --keys` (using [`DataKey::path`]), or
+//! to `icu_datagen` directly, to generate a blob with only the data this
+//! crate loads; see [`register_data_blob`](crate::register_data_blob).
+
+use std::collections::BTreeSet;
+
+use fluent_bundle::FluentResource;
+use icu_calendar::AnyCalendarKind;
+use icu_datetime::provider::{calendar, time_zones};
+use icu_provider::{data_key, DataKey, KeyedDataMarker};
+
+use crate::calendar::{is_enabled, PREFERRED};
+use crate::{scan, FluentDateTimeOptions};
+
+/// Every calendar the formatters know about
+const CALENDARS: &[AnyCalendarKind] = &[
+    AnyCalendarKind::Gregorian,
+    AnyCalendarKind::Buddhist,
+    AnyCalendarKind::Chinese,
+    AnyCalendarKind::Coptic,
+    AnyCalendarKind::Dangi,
+    AnyCalendarKind::Ethiopian,
+    AnyCalendarKind::EthiopianAmeteAlem,
+    AnyCalendarKind::Hebrew,
+    AnyCalendarKind::Indian,
+    AnyCalendarKind::IslamicCivil,
+    AnyCalendarKind::IslamicObservational,
+    AnyCalendarKind::IslamicTabular,
+    AnyCalendarKind::IslamicUmmAlQura,
+    AnyCalendarKind::Japanese,
+    AnyCalendarKind::JapaneseExtended,
+    AnyCalendarKind::Persian,
+    AnyCalendarKind::Roc,
+];
+
+/// What a set of formatters needs data for
+#[derive(Default)]
+struct Needs {
+    calendars: BTreeSet<usize>,
+    zoned: bool,
+    titlecase: bool,
+    relative: bool,
+}
+
+impl Needs {
+    fn add_calendar(&mut self, kind: AnyCalendarKind) {
+        let kind = match kind {
+            AnyCalendarKind::Iso => AnyCalendarKind::Gregorian,
+            kind => kind,
+        };
+        if is_enabled(kind) {
+            if let Some(i) = CALENDARS.iter().position(|&k| k == kind) {
+                self.calendars.insert(i);
+            }
+        }
+    }
+
+    fn add_all_calendars(&mut self) {
+        for &kind in CALENDARS {
+            self.add_calendar(kind);
+        }
+    }
+
+    fn add_options(&mut self, options: &FluentDateTimeOptions) {
+        match &options.calendar {
+            Some(ca) => {
+                if let Some(kind) = AnyCalendarKind::get_for_bcp47_value(ca) {
+                    self.add_calendar(kind);
+                }
+            }
+            // The locale default, see calendar::resolve
+            None => {
+                for kind in PREFERRED {
+                    self.add_calendar(kind);
+                }
+            }
+        }
+        self.zoned |= options.time_zone.is_some();
+        self.titlecase |= options
+            .casing
+            .is_some_and(|casing| casing.needs_titlecase());
+        self.relative |= options.relative.is_some();
+    }
+
+    fn keys(&self) -> Vec<DataKey> {
+        let mut keys = vec![
+            calendar::TimeLengthsV1Marker::KEY,
+            calendar::TimeSymbolsV1Marker::KEY,
+            icu_calendar::provider::WeekDataV1Marker::KEY,
+            data_key!("decimal/symbols@1"),
+            data_key!("plurals/ordinal@1"),
+            // DURATION
+            data_key!("plurals/cardinal@1"),
+            // Locale fallback for runtime providers
+            icu_locid_transform::provider::LocaleFallbackLikelySubtagsV1Marker::KEY,
+            icu_locid_transform::provider::LocaleFallbackParentsV1Marker::KEY,
+            icu_locid_transform::provider::CollationFallbackSupplementV1Marker::KEY,
+        ];
+        for &i in &self.calendars {
+            keys.extend_from_slice(calendar_keys(CALENDARS[i]));
+        }
+        if self.zoned {
+            keys.extend([
+                time_zones::ExemplarCitiesV1Marker::KEY,
+                time_zones::MetazoneGenericNamesLongV1Marker::KEY,
+                time_zones::MetazoneGenericNamesShortV1Marker::KEY,
+                time_zones::MetazoneSpecificNamesLongV1Marker::KEY,
+                time_zones::MetazoneSpecificNamesShortV1Marker::KEY,
+                time_zones::TimeZoneFormatsV1Marker::KEY,
+                icu_timezone::provider::MetazonePeriodV1Marker::KEY,
+                icu_timezone::provider::names::IanaToBcp47MapV2Marker::KEY,
+            ]);
+        }
+        // Always needed: year-month and month-day values, and years
+        // outside of 1-9999, are formatted from skeletons whatever the
+        // options
+        keys.push(calendar::DateSkeletonPatternsV1Marker::KEY);
+        if self.titlecase {
+            keys.extend([
+                icu_casemap::provider::CaseMapV1Marker::KEY,
+                data_key!("props/gc@1"),
+            ]);
+        }
+        if self.relative {
+            use icu_experimental::relativetime::provider::*;
+            keys.extend([
+                LongSecondRelativeTimeFormatDataV1Marker::KEY,
+                LongMinuteRelativeTimeFormatDataV1Marker::KEY,
+                LongHourRelativeTimeFormatDataV1Marker::KEY,
+                LongDayRelativeTimeFormatDataV1Marker::KEY,
+                LongWeekRelativeTimeFormatDataV1Marker::KEY,
+                LongMonthRelativeTimeFormatDataV1Marker::KEY,
+                LongYearRelativeTimeFormatDataV1Marker::KEY,
+                data_key!("plurals/cardinal@1"),
+            ]);
+        }
+        keys.sort_by(|a, b| a.path().get().cmp(b.path().get()));
+        keys.dedup();
+        keys
+    }
+}
+
+fn calendar_keys(kind: AnyCalendarKind) -> &'static [DataKey] {
+    use icu_calendar::provider as cal;
+    match kind {
+        AnyCalendarKind::Buddhist => &[
+            calendar::BuddhistDateLengthsV1Marker::KEY,
+            calendar::BuddhistDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::Chinese => &[
+            calendar::ChineseDateLengthsV1Marker::KEY,
+            calendar::ChineseDateSymbolsV1Marker::KEY,
+            cal::ChineseCacheV1Marker::KEY,
+        ],
+        AnyCalendarKind::Coptic => &[
+            calendar::CopticDateLengthsV1Marker::KEY,
+            calendar::CopticDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::Dangi => &[
+            calendar::DangiDateLengthsV1Marker::KEY,
+            calendar::DangiDateSymbolsV1Marker::KEY,
+            cal::DangiCacheV1Marker::KEY,
+        ],
+        AnyCalendarKind::Ethiopian | AnyCalendarKind::EthiopianAmeteAlem => &[
+            calendar::EthiopianDateLengthsV1Marker::KEY,
+            calendar::EthiopianDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::Hebrew => &[
+            calendar::HebrewDateLengthsV1Marker::KEY,
+            calendar::HebrewDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::Indian => &[
+            calendar::IndianDateLengthsV1Marker::KEY,
+            calendar::IndianDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::IslamicCivil | AnyCalendarKind::IslamicTabular => &[
+            calendar::IslamicDateLengthsV1Marker::KEY,
+            calendar::IslamicDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::IslamicObservational => &[
+            calendar::IslamicDateLengthsV1Marker::KEY,
+            calendar::IslamicDateSymbolsV1Marker::KEY,
+            cal::IslamicObservationalCacheV1Marker::KEY,
+        ],
+        AnyCalendarKind::IslamicUmmAlQura => &[
+            calendar::IslamicDateLengthsV1Marker::KEY,
+            calendar::IslamicDateSymbolsV1Marker::KEY,
+            cal::IslamicUmmAlQuraCacheV1Marker::KEY,
+        ],
+        AnyCalendarKind::Japanese => &[
+            calendar::JapaneseDateLengthsV1Marker::KEY,
+            calendar::JapaneseDateSymbolsV1Marker::KEY,
+            cal::JapaneseErasV1Marker::KEY,
+        ],
+        AnyCalendarKind::JapaneseExtended => &[
+            calendar::JapaneseExtendedDateLengthsV1Marker::KEY,
+            calendar::JapaneseExtendedDateSymbolsV1Marker::KEY,
+            cal::JapaneseExtendedErasV1Marker::KEY,
+        ],
+        AnyCalendarKind::Persian => &[
+            calendar::PersianDateLengthsV1Marker::KEY,
+            calendar::PersianDateSymbolsV1Marker::KEY,
+        ],
+        AnyCalendarKind::Roc => &[
+            calendar::RocDateLengthsV1Marker::KEY,
+            calendar::RocDateSymbolsV1Marker::KEY,
+        ],
+        _ => &[
+            calendar::GregorianDateLengthsV1Marker::KEY,
+            calendar::GregorianDateSymbolsV1Marker::KEY,
+        ],
+    }
+}
+
+/// Every data key the enabled features may need
+pub fn all_data_keys() -> Vec<DataKey> {
+    let mut needs = Needs {
+        zoned: true,
+        titlecase: true,
+        relative: true,
+        ..Default::default()
+    };
+    needs.add_all_calendars();
+    needs.keys()
+}
+
+/// The data keys needed to format datetimes with these options
+///
+/// Without a calendar option, this covers the locale default calendars.
+///
+/// ```
+/// use fluent_datetime::{data_keys, FluentDateTimeOptions};
+///
+/// let keys = data_keys(&FluentDateTimeOptions::default());
+/// assert!(keys.iter().any(|key| key.path().get() == "datetime/gregory/datelengths@1"));
+/// assert!(!keys.iter().any(|key| key.path().get() == "datetime/japanese/datelengths@1"));
+/// ```
+pub fn data_keys(options: &FluentDateTimeOptions) -> Vec<DataKey> {
+    let mut needs = Needs::default();
+    needs.add_options(options);
+    needs.keys()
+}
+
+/// The data keys needed to format the datetimes in these Fluent resources
+///
+/// This looks at the options of [`DATETIME`](crate::DATETIME) calls;
+/// a time zone given by a variable, as in `DATETIME($date, $tz)`, is
+/// assumed to be any zone.  Datetimes passed as bare variables get the
+/// default options.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::FluentResource;
+/// use fluent_datetime::data_keys_for_resources;
+///
+/// let resource = FluentResource::try_new(
+///     "now = {DATETIME($date, timeStyle: \"short\", timeZone: \"Europe/Berlin\")}".to_string(),
+/// ).unwrap();
+/// let keys = data_keys_for_resources([&resource]);
+/// assert!(keys.iter().any(|key| key.path().get() == "time_zone/formats@1"));
+/// assert!(!keys.iter().any(|key| key.path().get() == "relativetime/long/day@1"));
+/// // For year-month and month-day arguments
+/// assert!(keys.iter().any(|key| key.path().get() == "datetime/skeletons@1"));
+/// ```
+pub fn data_keys_for_resources<'a>(
+    resources: impl IntoIterator<Item = &'a FluentResource>,
+) -> Vec<DataKey> {
+    let mut needs = Needs::default();
+    needs.add_options(&FluentDateTimeOptions::default());
+    for call in scan::datetime_calls(resources) {
+        // Given at runtime, could be any time zone
+        needs.zoned |= call.dynamic_time_zone;
+        // Invalid options fail at runtime, before loading anything
+        if let Ok(options) = call.apply(FluentDateTimeOptions::default()) {
+            needs.add_options(&options);
+        }
+    }
+    needs.keys()
+}
@@ -0,0 +1,873 @@
+//! Durations, like "1 hr, 30 min"
+//!
+//! ICU 1.x has no unit names, so the worded styles come from an excerpt
+//! of the CLDR unit and list patterns, see the `duration_data` module;
+//! numbers and plural forms come from locale data.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use fixed_decimal::FixedDecimal;
+use fluent_bundle::types::FluentType;
+use fluent_bundle::{FluentArgs, FluentValue};
+use icu_calendar::types::Time;
+use icu_datetime::options::length;
+use icu_datetime::TimeFormatter;
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::FixedDecimalFormatter;
+use icu_locid::subtags::Language;
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::{DataErrorKind, DataLocale};
+use intl_memoizer::Memoizable;
+
+use crate::duration_data::{self, patterns};
+use crate::provider::{self, load, Fallback, SharedProvider};
+use crate::{report, val_as_str, val_to_string, DateTimeSupport, FluentDateTimeError};
+use crate::{GimmeTheLocale, LoadError};
+
+/// A unit of [`FluentDuration`], from the largest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DurationUnit {
+    /// 24 hours
+    Day,
+    /// 60 minutes
+    Hour,
+    /// 60 seconds
+    Minute,
+    /// The smallest unit
+    Second,
+}
+
+impl DurationUnit {
+    const ALL: [Self; 4] = [Self::Day, Self::Hour, Self::Minute, Self::Second];
+
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "day" => Self::Day,
+            "hour" => Self::Hour,
+            "minute" => Self::Minute,
+            "second" => Self::Second,
+            _ => return None,
+        })
+    }
+
+    fn seconds(self) -> u64 {
+        match self {
+            Self::Day => 86_400,
+            Self::Hour => 3600,
+            Self::Minute => 60,
+            Self::Second => 1,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// How durations are written, like the `style` option of
+/// `Intl.DurationFormat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DurationStyle {
+    /// Like "1 hour, 30 minutes"
+    Long,
+    /// Like "1 hr, 30 min"
+    #[default]
+    Short,
+    /// Like "1h 30m"
+    Narrow,
+    /// Like a clock, as in "1:30:00", for timers and media players
+    ///
+    /// Hours are shown from an hour on, and days are counted as hours.
+    /// Digits and the separator are the locale's, like in times of day.
+    /// Unlike the worded styles, this works in every locale; the others
+    /// fall back to it in languages without built-in unit names.
+    ///
+    /// ```
+    /// use fluent_datetime::{DurationStyle, DurationUnit, FluentDuration};
+    ///
+    /// let digital = |o: &mut fluent_datetime::DurationOptions| o.set_style(Some(DurationStyle::Digital));
+    /// let movie = FluentDuration::from_secs(5025).map_options(digital);
+    /// assert_eq!(movie.format(&"en-US".parse()?).unwrap(), "1:23:45");
+    /// assert_eq!(movie.format(&"fi-FI".parse()?).unwrap(), "1.23.45");
+    /// assert_eq!(movie.format(&"ar-EG".parse()?).unwrap(), "١:٢٣:٤٥");
+    /// let song = FluentDuration::new(0, 0, 3, 7).map_options(digital);
+    /// assert_eq!(song.format(&"en-US".parse()?).unwrap(), "3:07");
+    /// let timer = FluentDuration::new(1, 2, 0, 0)
+    ///     .map_options(digital)
+    ///     .map_options(|o| o.set_units(Some(&[DurationUnit::Hour, DurationUnit::Minute])));
+    /// assert_eq!(timer.format(&"en-US".parse()?).unwrap(), "26:00");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    Digital,
+}
+
+/// Options for formatting a [`FluentDuration`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DurationOptions {
+    style: Option<DurationStyle>,
+    // A bit per DurationUnit, None for the fields as given
+    units: Option<u8>,
+    largest_unit: Option<DurationUnit>,
+    max_units: Option<usize>,
+    // Set by DURATION in bundles with their own data source
+    provider: Option<SharedProvider>,
+}
+
+impl DurationOptions {
+    /// Sets how verbose units are, [`DurationStyle::Short`] by default
+    pub fn set_style(&mut self, style: Option<DurationStyle>) {
+        self.style = style;
+    }
+
+    /// Sets the units to show
+    ///
+    /// The duration is spread over these units: a day and a half shown in
+    /// hours is 36 hours, and what is left below the smallest unit is
+    /// dropped.  Units that come out as zero are left out.  By default,
+    /// the fields of the duration are shown as they were given.
+    ///
+    /// ```
+    /// use fluent_datetime::{DurationUnit, FluentDuration};
+    ///
+    /// let duration = FluentDuration::new(1, 12, 0, 30)
+    ///     .map_options(|o| o.set_units(Some(&[DurationUnit::Hour, DurationUnit::Minute])));
+    /// assert_eq!(duration.format(&"en-US".parse()?).unwrap(), "36 hr");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_units(&mut self, units: Option<&[DurationUnit]>) {
+        self.units = units.map(|units| units.iter().fold(0, |mask, unit| mask | unit.bit()));
+    }
+
+    /// Sets the largest unit to show
+    ///
+    /// Larger units are counted in this one, so a day and two hours with
+    /// hours as the largest unit is 26 hours.  When no units are set, the
+    /// duration is spread over this unit and the smaller ones.
+    pub fn set_largest_unit(&mut self, unit: Option<DurationUnit>) {
+        self.largest_unit = unit;
+    }
+
+    /// Shows at most this many units, starting from the largest that isn't
+    /// zero, and drops the rest
+    ///
+    /// This makes long durations easier to read, "2h 5m" rather than
+    /// "2h 5m 33s".  At least one unit is shown.  The digital style shows
+    /// every unit, like a clock.
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, DurationStyle, DurationUnit, FluentDuration, IntlSupport};
+    ///
+    /// let elapsed = FluentDuration::from_secs(93_933).map_options(|o| {
+    ///     o.set_style(Some(DurationStyle::Narrow));
+    ///     o.set_largest_unit(Some(DurationUnit::Hour));
+    ///     o.set_max_units(Some(2));
+    /// });
+    /// assert_eq!(elapsed.format(&"en-US".parse()?).unwrap(), "26h 5m");
+    /// let elapsed = FluentDuration::new(0, 2, 0, 33).map_options(|o| o.set_max_units(Some(2)));
+    /// assert_eq!(elapsed.format(&"en-US".parse()?).unwrap(), "2 hr");
+    ///
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.set_use_isolating(false);
+    /// bundle.add_intl_support(IntlSupport::new().with_duration())?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "elapsed = {DURATION($seconds, largestUnit: \"minute\", maximumUnits: 1)} ago".to_string(),
+    /// ).unwrap()).unwrap();
+    /// let message = bundle.get_message("elapsed").unwrap().value().unwrap();
+    /// let args = FluentArgs::from_iter([("seconds", 5025)]);
+    /// assert_eq!(bundle.format_pattern(message, Some(&args), &mut vec![]), "83 min ago");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_max_units(&mut self, max_units: Option<usize>) {
+        self.max_units = max_units;
+    }
+
+    /// Merges the named options of a DURATION call
+    ///
+    /// `style` is `long`, `short`, `narrow` or `digital`, and `units` lists units
+    /// among `day`, `hour`, `minute` and `second`, separated by spaces or
+    /// commas.  `largestUnit` is one of these units, and `maximumUnits` a
+    /// number of units, see [`set_largest_unit`](Self::set_largest_unit)
+    /// and [`set_max_units`](Self::set_max_units).  Unknown options are
+    /// ignored; invalid ones are reported, after the others have been
+    /// merged.
+    pub fn merge_args(&mut self, other: &FluentArgs) -> Result<(), FluentDateTimeError> {
+        let mut result = Ok(());
+        for (k, v) in other.iter() {
+            if let Err(e) = self.merge_arg(k, v) {
+                result = result.and(Err(e));
+            }
+        }
+        result
+    }
+
+    fn merge_arg(&mut self, k: &str, v: &FluentValue) -> Result<(), FluentDateTimeError> {
+        let as_str = || {
+            val_as_str(v).ok_or_else(|| FluentDateTimeError::WrongOptionType {
+                name: k.to_string(),
+                expected: "a string",
+            })
+        };
+        let invalid = || FluentDateTimeError::InvalidOption {
+            name: k.to_string(),
+            value: val_to_string(v),
+        };
+        match k {
+            "style" => {
+                self.style = Some(match as_str()? {
+                    "long" => DurationStyle::Long,
+                    "short" => DurationStyle::Short,
+                    "narrow" => DurationStyle::Narrow,
+                    "digital" => DurationStyle::Digital,
+                    _ => return Err(invalid()),
+                });
+            }
+            "units" => {
+                let units = as_str()?
+                    .split([' ', ','])
+                    .filter(|unit| !unit.is_empty())
+                    .map(DurationUnit::from_keyword)
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|units| !units.is_empty())
+                    .ok_or_else(invalid)?;
+                self.set_units(Some(&units));
+            }
+            "largestUnit" => {
+                self.largest_unit =
+                    Some(DurationUnit::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "maximumUnits" => {
+                let FluentValue::Number(n) = v else {
+                    return Err(FluentDateTimeError::WrongOptionType {
+                        name: k.to_string(),
+                        expected: "a number",
+                    });
+                };
+                if n.value.fract() != 0.0 || n.value < 1.0 {
+                    return Err(invalid());
+                }
+                self.max_units = Some(n.value as usize);
+            }
+            _ => (), // Ignore with no warning
+        }
+        Ok(())
+    }
+
+    /// The units to spread the duration over, a bit per unit, None for
+    /// the fields as given
+    fn unit_mask(&self) -> Option<u8> {
+        let Some(largest) = self.largest_unit else {
+            return self.units;
+        };
+        let below = DurationUnit::ALL
+            .into_iter()
+            .filter(|&unit| unit >= largest)
+            .fold(0, |mask, unit| mask | unit.bit());
+        match self.units.map(|mask| mask & below) {
+            None => Some(below),
+            // Only larger units were asked for
+            Some(0) => Some(largest.bit()),
+            Some(mask) => Some(mask),
+        }
+    }
+}
+
+/// A length of time, like a video's running time or a flight's
+///
+/// This formats as "1 hr, 30 min", with the [`DurationOptions`] attached
+/// to it.  The fields are kept as given, so 90 minutes stay 90 minutes
+/// unless [`DurationOptions::set_units`] spreads them over other units.
+/// [`DURATION`] takes durations, and numbers of seconds.
+///
+/// The worded styles are built in for English, French, German and
+/// Spanish; other languages get the [digital](DurationStyle::Digital)
+/// style, with their own digits and separator.  When locale data is
+/// missing, the duration is rendered as ISO 8601 (`PT1H30M`) and the
+/// error is reported.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, DurationStyle, FluentDuration, IntlSupport};
+///
+/// let duration = FluentDuration::new(0, 1, 30, 0);
+/// assert_eq!(duration.format(&"en-US".parse()?).unwrap(), "1 hr, 30 min");
+/// let duration = duration.map_options(|o| o.set_style(Some(DurationStyle::Long)));
+/// assert_eq!(duration.format(&"en-US".parse()?).unwrap(), "1 hour, 30 minutes");
+/// assert_eq!(duration.format(&"fr-FR".parse()?).unwrap(), "1 heure et 30 minutes");
+/// assert_eq!(duration.format(&"ja-JP".parse()?).unwrap(), "1:30:00");
+///
+/// let mut bundle = FluentBundle::new(vec!["de-DE".parse()?]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_intl_support(IntlSupport::new().with_duration())?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "running-time = Laufzeit: {$duration}
+/// flight = Flugzeit: {DURATION($seconds, style: \"long\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let mut args = FluentArgs::new();
+/// args.set("duration", FluentDuration::from_secs(5400));
+/// args.set("seconds", 41_400);
+/// let mut errors = vec![];
+/// let running_time = bundle.get_message("running-time").unwrap().value().unwrap();
+/// assert_eq!(
+///     bundle.format_pattern(running_time, Some(&args), &mut errors),
+///     "Laufzeit: 1 Std., 30 Min."
+/// );
+/// let flight = bundle.get_message("flight").unwrap().value().unwrap();
+/// assert_eq!(
+///     bundle.format_pattern(flight, Some(&args), &mut errors),
+///     "Flugzeit: 11 Stunden und 30 Minuten"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FluentDuration {
+    days: u64,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    /// Options for rendering
+    pub options: DurationOptions,
+}
+
+impl FluentDuration {
+    /// A duration with these fields, which can exceed their usual range,
+    /// as in 90 minutes
+    pub fn new(days: u64, hours: u64, minutes: u64, seconds: u64) -> Self {
+        Self {
+            days,
+            hours,
+            minutes,
+            seconds,
+            options: DurationOptions::default(),
+        }
+    }
+
+    /// A number of seconds, spread over days, hours, minutes and seconds
+    pub fn from_secs(secs: u64) -> Self {
+        Self::new(
+            secs / 86_400,
+            secs % 86_400 / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+        )
+    }
+
+    /// The days field
+    pub fn days(&self) -> u64 {
+        self.days
+    }
+
+    /// The hours field
+    pub fn hours(&self) -> u64 {
+        self.hours
+    }
+
+    /// The minutes field
+    pub fn minutes(&self) -> u64 {
+        self.minutes
+    }
+
+    /// The seconds field
+    pub fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// The whole duration in seconds, saturating at `u64::MAX`
+    pub fn as_secs(&self) -> u64 {
+        self.fields()
+            .into_iter()
+            .fold(0u64, |total, (unit, value)| {
+                total.saturating_add(value.saturating_mul(unit.seconds()))
+            })
+    }
+
+    /// Changes the formatting options, in a chain
+    pub fn map_options(mut self, f: impl FnOnce(&mut DurationOptions)) -> Self {
+        f(&mut self.options);
+        self
+    }
+
+    /// Formats with the attached options, for an explicit locale
+    ///
+    /// Fails if the locale data can't be loaded.
+    pub fn format(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<String, FluentDateTimeError> {
+        Ok(self.try_format(locale)?)
+    }
+
+    /// Renders as ISO 8601, like `P1DT2H30M`
+    pub fn to_iso8601(&self) -> String {
+        let mut s = "P".to_string();
+        if self.days != 0 {
+            let _ = write!(s, "{}D", self.days);
+        }
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.days == 0 {
+            s.push('T');
+        }
+        for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+            if value != 0 {
+                let _ = write!(s, "{value}{designator}");
+            }
+        }
+        if self.seconds != 0 || s == "PT" {
+            let _ = write!(s, "{}S", self.seconds);
+        }
+        s
+    }
+
+    fn fields(&self) -> [(DurationUnit, u64); 4] {
+        [
+            (DurationUnit::Day, self.days),
+            (DurationUnit::Hour, self.hours),
+            (DurationUnit::Minute, self.minutes),
+            (DurationUnit::Second, self.seconds),
+        ]
+    }
+
+    /// The units to write, with their values
+    fn values(&self) -> Vec<(DurationUnit, u64)> {
+        let values = match self.options.unit_mask() {
+            None => self.fields().to_vec(),
+            Some(mask) => {
+                let mut rest = self.as_secs();
+                DurationUnit::ALL
+                    .into_iter()
+                    .filter(|unit| mask & unit.bit() != 0)
+                    .map(|unit| {
+                        let value = rest / unit.seconds();
+                        rest %= unit.seconds();
+                        (unit, value)
+                    })
+                    .collect()
+            }
+        };
+        let smallest = values
+            .last()
+            .map_or(DurationUnit::Second, |&(unit, _)| unit);
+        let Some(first) = values.iter().position(|&(_, value)| value != 0) else {
+            return vec![(smallest, 0)];
+        };
+        let max_units = self.options.max_units.unwrap_or(values.len()).max(1);
+        values[first..]
+            .iter()
+            .take(max_units)
+            .filter(|&&(_, value)| value != 0)
+            .copied()
+            .collect()
+    }
+
+    fn try_format(&self, locale: &unic_langid::LanguageIdentifier) -> Result<String, LoadError> {
+        let formatter = DurationFormatter::construct(locale.clone(), self.formatter_args())?;
+        self.write_with(&formatter)
+    }
+
+    /// What the formatter for this duration depends on, besides the locale
+    fn formatter_args(&self) -> FormatterArgs {
+        FormatterArgs {
+            digital: self.options.style == Some(DurationStyle::Digital),
+            provider: self.options.provider.clone(),
+            generation: provider::generation(),
+        }
+    }
+
+    fn write_with(&self, formatter: &DurationFormatter) -> Result<String, LoadError> {
+        let (plurals, patterns) = match &formatter.kind {
+            Kind::Digital(separator) => return Ok(self.digital(&formatter.decimals, separator)),
+            Kind::Worded(plurals) => {
+                let style = self.options.style.unwrap_or_default();
+                let patterns = patterns(formatter.language, style).ok_or(LoadError::Data(
+                    DataErrorKind::MissingLocale.with_str_context("duration units"),
+                ))?;
+                (plurals, patterns)
+            }
+        };
+        let values = self.values();
+        let mut s = String::new();
+        for (i, &(unit, value)) in values.iter().enumerate() {
+            if i + 1 == values.len() && i > 0 {
+                s.push_str(patterns.last);
+            } else if i > 0 {
+                s.push_str(patterns.between);
+            }
+            let (one, other) = patterns.units[unit as usize];
+            s.push_str(
+                &formatter
+                    .decimals
+                    .format_to_string(&FixedDecimal::from(value)),
+            );
+            s.push_str(match plurals.category_for(value) {
+                PluralCategory::One => one,
+                _ => other,
+            });
+        }
+        Ok(s)
+    }
+
+    /// Writes the duration like a clock, see [`DurationStyle::Digital`]
+    fn digital(&self, decimals: &FixedDecimalFormatter, separator: &str) -> String {
+        // Days count as hours, and hours are left out of short durations
+        // unless asked for
+        let units = match self.options.unit_mask() {
+            Some(mask) if mask & DurationUnit::Day.bit() != 0 => mask | DurationUnit::Hour.bit(),
+            Some(mask) => mask,
+            None if self.as_secs() < 3600 => {
+                DurationUnit::Minute.bit() | DurationUnit::Second.bit()
+            }
+            None => {
+                DurationUnit::Hour.bit() | DurationUnit::Minute.bit() | DurationUnit::Second.bit()
+            }
+        };
+        let mut rest = self.as_secs();
+        let mut s = String::new();
+        for unit in [
+            DurationUnit::Hour,
+            DurationUnit::Minute,
+            DurationUnit::Second,
+        ] {
+            if units & unit.bit() == 0 {
+                continue;
+            }
+            let value = FixedDecimal::from(rest / unit.seconds());
+            rest %= unit.seconds();
+            if s.is_empty() {
+                s.push_str(&decimals.format_to_string(&value));
+            } else {
+                s.push_str(separator);
+                s.push_str(&decimals.format_to_string(&value.padded_start(2)));
+            }
+        }
+        s
+    }
+
+    /// Renders with a formatted string or the error, falling back to
+    /// ISO 8601
+    fn render(
+        &self,
+        lang: &unic_langid::LanguageIdentifier,
+        formatted: Result<String, LoadError>,
+    ) -> Cow<'static, str> {
+        match formatted {
+            Ok(s) => s.into(),
+            Err(e) => {
+                let iso = self.to_iso8601();
+                report::report(|| {
+                    format!("DURATION: could not format {iso} for locale {lang}: {e}")
+                });
+                iso.into()
+            }
+        }
+    }
+}
+
+/// Parses ISO 8601 durations, like `PT1H30M` or `P1DT12H`
+///
+/// Weeks count as 7 days.  Years and months don't have a fixed length
+/// and are rejected.  Seconds can have a fraction, which is dropped.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, FluentDuration, IntlSupport};
+///
+/// let duration: FluentDuration = "PT1H30M".parse()?;
+/// assert_eq!((duration.hours(), duration.minutes()), (1, 30));
+/// assert_eq!(duration.format(&"en-US".parse()?).unwrap(), "1 hr, 30 min");
+/// assert_eq!("P2W".parse::<FluentDuration>()?.days(), 14);
+/// assert_eq!("PT90.5S".parse::<FluentDuration>()?.as_secs(), 90);
+/// assert!("P1M".parse::<FluentDuration>().is_err());
+/// assert!("PT".parse::<FluentDuration>().is_err());
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_intl_support(IntlSupport::new().with_duration())?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "flight = {DURATION($duration, style: \"long\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let flight = bundle.get_message("flight").unwrap().value().unwrap();
+/// let args = FluentArgs::from_iter([("duration", "PT11H30M")]);
+/// assert_eq!(bundle.format_pattern(flight, Some(&args), &mut vec![]), "11 hours, 30 minutes");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl FromStr for FluentDuration {
+    type Err = FluentDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FluentDateTimeError::InvalidDuration(s.to_string());
+        let rest = s.strip_prefix('P').ok_or_else(invalid)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) if !time.is_empty() => (date, time),
+            None if !rest.is_empty() => (rest, ""),
+            _ => return Err(invalid()),
+        };
+        let mut duration = Self::new(0, 0, 0, 0);
+        for (value, designator) in components(date, "WD").ok_or_else(invalid)? {
+            let days = match designator {
+                'W' => value.checked_mul(7).ok_or_else(invalid)?,
+                _ => value,
+            };
+            duration.days = duration.days.checked_add(days).ok_or_else(invalid)?;
+        }
+        for (value, designator) in components(time, "HMS").ok_or_else(invalid)? {
+            match designator {
+                'H' => duration.hours = value,
+                'M' => duration.minutes = value,
+                _ => duration.seconds = value,
+            }
+        }
+        Ok(duration)
+    }
+}
+
+/// The values of a part of an ISO 8601 duration, in the order of
+/// `designators`, each at most once
+fn components(mut s: &str, designators: &str) -> Option<Vec<(u64, char)>> {
+    let mut components = vec![];
+    let mut designators = designators.chars();
+    while !s.is_empty() {
+        let end = s.find(|c: char| c.is_ascii_alphabetic())?;
+        let designator = s[end..].chars().next()?;
+        designators.by_ref().find(|&d| d == designator)?;
+        let value = match s[..end].split_once(['.', ',']) {
+            Some((whole, fraction))
+                if designator == 'S'
+                    && !fraction.is_empty()
+                    && fraction.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                whole
+            }
+            Some(_) => return None,
+            None => &s[..end],
+        };
+        if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        components.push((value.parse().ok()?, designator));
+        s = &s[end + 1..];
+    }
+    Some(components)
+}
+
+impl From<std::time::Duration> for FluentDuration {
+    /// Whole seconds, see [`FluentDuration::from_secs`]
+    fn from(value: std::time::Duration) -> Self {
+        Self::from_secs(value.as_secs())
+    }
+}
+
+impl FluentType for FluentDuration {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let formatted = intls
+            .with_try_get::<DurationFormatter, _, _>(self.formatter_args(), |formatter| {
+                self.write_with(formatter)
+            })
+            .and_then(|formatted| formatted);
+        self.render(&lang, formatted)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let formatted = intls
+            .with_try_get::<DurationFormatter, _, _>(self.formatter_args(), |formatter| {
+                self.write_with(formatter)
+            })
+            .and_then(|formatted| formatted);
+        self.render(&lang, formatted)
+    }
+}
+
+impl From<FluentDuration> for FluentValue<'static> {
+    fn from(value: FluentDuration) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+/// A Fluent function for durations
+///
+/// Register it with [`IntlSupport::with_duration`](crate::IntlSupport::with_duration);
+/// [`BundleExt::add_datetime_support`](crate::BundleExt::add_datetime_support)
+/// leaves it out.
+///
+/// ```fluent
+/// running-time = Running time: {DURATION($duration, style: "long")}
+/// elapsed = {DURATION($seconds, units: "hour minute")}
+/// uptime = {DURATION($seconds, largestUnit: "hour", maximumUnits: 2)}
+/// ```
+///
+/// The first argument is a [`FluentDuration`], a number of seconds,
+/// which is spread over days, hours, minutes and seconds, or an ISO 8601
+/// duration like `"PT1H30M"`, as APIs often send them, see [`FromStr`]
+/// for [`FluentDuration`].  See
+/// [`DurationOptions::merge_args`] for the options.  Invalid values are
+/// reported and the duration is formatted without them.
+///
+/// The unit names of the worded styles are built in for English, French,
+/// German and Spanish, rather than loaded from locale data or the
+/// bundle's data source; other languages get the digital style, see
+/// [`FluentDuration`].
+#[allow(non_snake_case)]
+pub fn DURATION<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    duration_with_support(positional, named, &DateTimeSupport::default())
+}
+
+pub(crate) fn duration_with_support<'a>(
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+    support: &DateTimeSupport,
+) -> FluentValue<'a> {
+    let mut duration = match resolve_duration(positional.first()) {
+        Ok(duration) => duration,
+        Err(description) => {
+            report::report(|| format!("DURATION: {description}"));
+            return FluentValue::Error;
+        }
+    };
+    if support.provider.is_some() {
+        duration.options.provider = support.provider.clone();
+    }
+    if let Err(e) = duration.options.merge_args(named) {
+        report::report(|| format!("DURATION: {e}"));
+    }
+    FluentValue::Custom(Box::new(duration))
+}
+
+fn resolve_duration(value: Option<&FluentValue>) -> Result<FluentDuration, String> {
+    match value {
+        Some(FluentValue::Custom(cus)) => {
+            if let Some(duration) = cus.as_any().downcast_ref::<FluentDuration>() {
+                return Ok(duration.clone());
+            }
+        }
+        Some(FluentValue::Number(n)) if n.value >= 0.0 && n.value.is_finite() => {
+            return Ok(FluentDuration::from_secs(n.value as u64));
+        }
+        Some(FluentValue::String(s)) => return s.parse().map_err(|e| format!("{e}")),
+        _ => (),
+    }
+    Err(format!("not a duration: {value:?}"))
+}
+
+/// The memoizer key of a [`DurationFormatter`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct FormatterArgs {
+    // Whether the digital style was asked for
+    digital: bool,
+    provider: Option<SharedProvider>,
+    /// See [`provider::generation`]
+    generation: u64,
+}
+
+/// The locale data of a language for formatting durations, memoized by
+/// bundles
+struct DurationFormatter {
+    language: Language,
+    decimals: FixedDecimalFormatter,
+    kind: Kind,
+}
+
+enum Kind {
+    /// What goes between hours, minutes and seconds
+    Digital(String),
+    /// The plural rules, for unit names
+    Worded(PluralRules),
+}
+
+impl intl_memoizer::Memoizable for DurationFormatter {
+    type Args = FormatterArgs;
+
+    type Error = LoadError;
+
+    fn construct(
+        lang: unic_langid::LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: std::marker::Sized,
+    {
+        let langid: icu_locid::LanguageIdentifier = lang
+            .to_string()
+            .parse()
+            .map_err(|_| LoadError::InvalidLocale)?;
+        let provider = match &args.provider {
+            Some(provider) => provider.get(),
+            None => provider::default_provider().ok_or(LoadError::NoProvider)?,
+        };
+        let fallback = Fallback::new(provider);
+        let language = langid.language;
+        let locale = DataLocale::from(langid);
+        // Without built-in unit names, the worded styles are digital too
+        let digital = args.digital || !duration_data::has_language(language);
+        let mut decimal_options = FixedDecimalFormatterOptions::default();
+        if digital {
+            decimal_options.grouping_strategy = GroupingStrategy::Never;
+        }
+        let decimals = load!(
+            fallback.get(),
+            FixedDecimalFormatter::try_new,
+            FixedDecimalFormatter::try_new_with_any_provider,
+            FixedDecimalFormatter::try_new_with_buffer_provider,
+            &locale,
+            decimal_options,
+        )
+        .map_err(|e| match e {
+            icu_decimal::DecimalError::Data(e) => LoadError::Data(e),
+            _ => LoadError::Data(DataErrorKind::Custom.into_error()),
+        })?;
+        let kind = if digital {
+            Kind::Digital(time_separator(&load!(
+                fallback.get(),
+                TimeFormatter::try_new_with_length,
+                TimeFormatter::try_new_with_length_with_any_provider,
+                TimeFormatter::try_new_with_length_with_buffer_provider,
+                &locale,
+                length::Time::Short,
+            )?))
+        } else {
+            Kind::Worded(
+                load!(
+                    fallback.get(),
+                    PluralRules::try_new_cardinal,
+                    PluralRules::try_new_cardinal_with_any_provider,
+                    PluralRules::try_new_cardinal_with_buffer_provider,
+                    &locale,
+                )
+                .map_err(|e| match e {
+                    icu_plurals::PluralsError::Data(e) => LoadError::Data(e),
+                    _ => LoadError::Data(DataErrorKind::Custom.into_error()),
+                })?,
+            )
+        };
+        Ok(Self {
+            language,
+            decimals,
+            kind,
+        })
+    }
+}
+
+/// What the locale writes between hours and minutes, like ":" or "."
+fn time_separator(times: &TimeFormatter) -> String {
+    let time = Time::try_new(11, 22, 0, 0).expect("Valid time");
+    let s = times.format_to_string(&time);
+    s.trim_start_matches(|c: char| !c.is_numeric())
+        .trim_start_matches(char::is_numeric)
+        .split(char::is_numeric)
+        .next()
+        .filter(|separator| !separator.is_empty())
+        .unwrap_or(":")
+        .to_string()
+}
@@ -0,0 +1,148 @@
+//! The unit and list patterns of the worded duration styles
+//!
+//! ICU4X 1.5 has no unit formatter, so this is an excerpt of CLDR 45, the
+//! version its compiled data comes from, for English, French, German and
+//! Spanish:
+//!
+//! * the `unitPattern-count-one` and `unitPattern-count-other` of
+//!   `duration-day`, `duration-hour`, `duration-minute` and
+//!   `duration-second` in `cldr-units-full/main/<lang>/units.json`, from
+//!   the `long`, `short` and `narrow` sections, without the `{0}`
+//! * the `middle` and `end` of `listPattern-type-unit`,
+//!   `listPattern-type-unit-short` and `listPattern-type-unit-narrow` in
+//!   `cldr-misc-full/main/<lang>/listPatterns.json`
+//!
+//! Keep the table in sync with those files when updating ICU4X; other
+//! languages use the digital style.
+
+use icu_locid::subtags::Language;
+
+use crate::DurationStyle;
+
+/// The CLDR patterns of a language, in one style
+pub(crate) struct Patterns {
+    /// What follows the number for each unit, from days to seconds, in
+    /// the plural forms one and other
+    pub(crate) units: [(&'static str, &'static str); 4],
+    /// What goes between units, and before the last one
+    pub(crate) between: &'static str,
+    pub(crate) last: &'static str,
+}
+
+/// Whether the worded styles are built in for a language
+pub(crate) fn has_language(lang: Language) -> bool {
+    patterns(lang, DurationStyle::Short).is_some()
+}
+
+/// The CLDR unit and list patterns of a language, if built in
+pub(crate) fn patterns(lang: Language, style: DurationStyle) -> Option<&'static Patterns> {
+    use DurationStyle::*;
+    Some(match (lang.as_str(), style) {
+        ("en", Long) => &Patterns {
+            units: [
+                (" day", " days"),
+                (" hour", " hours"),
+                (" minute", " minutes"),
+                (" second", " seconds"),
+            ],
+            between: ", ",
+            last: ", ",
+        },
+        ("en", Short) => &Patterns {
+            units: [
+                (" day", " days"),
+                (" hr", " hr"),
+                (" min", " min"),
+                (" sec", " sec"),
+            ],
+            between: ", ",
+            last: ", ",
+        },
+        ("en", Narrow) => &Patterns {
+            units: [("d", "d"), ("h", "h"), ("m", "m"), ("s", "s")],
+            between: " ",
+            last: " ",
+        },
+        ("de", Long) => &Patterns {
+            units: [
+                (" Tag", " Tage"),
+                (" Stunde", " Stunden"),
+                (" Minute", " Minuten"),
+                (" Sekunde", " Sekunden"),
+            ],
+            between: ", ",
+            last: " und ",
+        },
+        ("de", Short) => &Patterns {
+            units: [
+                (" Tg.", " Tg."),
+                (" Std.", " Std."),
+                (" Min.", " Min."),
+                (" Sek.", " Sek."),
+            ],
+            between: ", ",
+            last: ", ",
+        },
+        ("de", Narrow) => &Patterns {
+            units: [
+                (" T", " T"),
+                (" Std.", " Std."),
+                (" Min.", " Min."),
+                (" Sek.", " Sek."),
+            ],
+            between: " ",
+            last: " ",
+        },
+        ("fr", Long) => &Patterns {
+            units: [
+                (" jour", " jours"),
+                (" heure", " heures"),
+                (" minute", " minutes"),
+                (" seconde", " secondes"),
+            ],
+            between: ", ",
+            last: " et ",
+        },
+        ("fr", Short) => &Patterns {
+            units: [
+                ("\u{a0}j", "\u{a0}j"),
+                ("\u{a0}h", "\u{a0}h"),
+                ("\u{a0}min", "\u{a0}min"),
+                ("\u{a0}s", "\u{a0}s"),
+            ],
+            between: ", ",
+            last: " et ",
+        },
+        ("fr", Narrow) => &Patterns {
+            units: [("j", "j"), ("h", "h"), ("min", "min"), ("s", "s")],
+            between: " ",
+            last: " ",
+        },
+        ("es", Long) => &Patterns {
+            units: [
+                (" día", " días"),
+                (" hora", " horas"),
+                (" minuto", " minutos"),
+                (" segundo", " segundos"),
+            ],
+            between: ", ",
+            last: " y ",
+        },
+        ("es", Short) => &Patterns {
+            units: [
+                ("\u{a0}d", "\u{a0}d"),
+                ("\u{a0}h", "\u{a0}h"),
+                ("\u{a0}min", "\u{a0}min"),
+                ("\u{a0}s", "\u{a0}s"),
+            ],
+            between: ", ",
+            last: " y ",
+        },
+        ("es", Narrow) => &Patterns {
+            units: [("d", "d"), ("h", "h"), ("min", "min"), ("s", "s")],
+            between: " ",
+            last: " ",
+        },
+        _ => return None,
+    })
+}
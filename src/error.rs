@@ -0,0 +1,211 @@
+//! Why a datetime formatter couldn't be built
+
+use std::fmt;
+
+use fluent_bundle::FluentError;
+use icu_calendar::CalendarError;
+use icu_datetime::DateTimeError;
+use icu_provider::{DataError, DataErrorKind};
+
+/// Why a datetime formatter couldn't be built
+///
+/// ```
+/// use fluent_datetime::{FluentDateTimeFormatter, FluentDateTimeOptions, LoadError};
+///
+/// let mut options = FluentDateTimeOptions::default();
+/// options.set_calendar(Some("nonsense".parse().unwrap()));
+/// assert_eq!(
+///     FluentDateTimeFormatter::try_new(&"en-US".parse()?, options).unwrap_err(),
+///     LoadError::UnsupportedCalendar
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// There is no data source: the `compiled_data` feature is disabled
+    /// and no provider was given
+    NoProvider,
+    /// The locale isn't valid for ICU
+    InvalidLocale,
+    /// Locale data is missing or couldn't be loaded
+    Data(DataError),
+    /// The requested calendar is unknown, or its cargo feature isn't enabled
+    UnsupportedCalendar,
+    /// ICU can't build a formatter for these options
+    Options(DateTimeError),
+}
+
+impl LoadError {
+    /// Whether this is due to missing locale data, as opposed to invalid
+    /// options or a broken data source
+    pub fn is_missing_data(&self) -> bool {
+        matches!(
+            self,
+            Self::NoProvider
+                | Self::Data(DataError {
+                    kind: DataErrorKind::MissingLocale | DataErrorKind::MissingDataKey,
+                    ..
+                })
+        )
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoProvider => f.write_str("no locale data source"),
+            Self::InvalidLocale => f.write_str("invalid locale"),
+            Self::Data(e) => write!(f, "locale data: {e}"),
+            Self::UnsupportedCalendar => f.write_str("calendar not supported or not enabled"),
+            Self::Options(e) => write!(f, "unsupported options: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<DataError> for LoadError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+impl From<DateTimeError> for LoadError {
+    fn from(e: DateTimeError) -> Self {
+        match e {
+            DateTimeError::Data(e) => Self::Data(e),
+            e => Self::Options(e),
+        }
+    }
+}
+
+impl From<CalendarError> for LoadError {
+    fn from(e: CalendarError) -> Self {
+        DateTimeError::from(e).into()
+    }
+}
+
+/// Errors of the fluent-datetime API
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, FluentDateTimeError};
+///
+/// let mut bundle = FluentBundle::<FluentResource>::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support()?;
+/// // DATETIME is already taken
+/// assert!(matches!(
+///     bundle.add_datetime_support(),
+///     Err(FluentDateTimeError::Fluent(_))
+/// ));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum FluentDateTimeError {
+    /// An option has a value it doesn't accept, like `dateStyle: "fulll"`
+    InvalidOption {
+        /// The option name, as in Fluent, for example `dateStyle`
+        name: String,
+        /// The rejected value, as written in Fluent
+        value: String,
+    },
+    /// An option has a value of the wrong type, like `dateStyle: 3`
+    WrongOptionType {
+        /// The option name, as in Fluent, for example `dateStyle`
+        name: String,
+        /// What the option takes, for example `a string`
+        expected: &'static str,
+    },
+    /// An option [`DATETIME`](crate::DATETIME) doesn't know, reported by
+    /// [`validate_resource_strict`](crate::validate_resource_strict)
+    UnknownOption(String),
+    /// The datetime argument of [`DATETIME`](crate::DATETIME) is missing,
+    /// or isn't a datetime
+    MissingDateTime,
+    /// A time zone was given for a [floating](crate::Semantics::Floating)
+    /// datetime
+    FloatingTimeZone,
+    /// The text isn't a valid ISO 8601 date or datetime
+    InvalidDateTime(String),
+    /// The year is outside of [`FluentDateTime::YEARS`](crate::FluentDateTime::YEARS)
+    YearOutOfRange(i32),
+    /// The text isn't a valid ISO 8601 duration, or one with years or
+    /// months, see [`FluentDuration`](crate::FluentDuration)
+    InvalidDuration(String),
+    /// The text isn't a date [`DateParser`](crate::DateParser) can read
+    UnrecognizedDate(String),
+    /// A strftime directive [`StrftimeConversion`](crate::StrftimeConversion)
+    /// doesn't know, like `%Q`
+    UnknownDirective(String),
+    /// A [`FluentDateInterval`](crate::FluentDateInterval) ends before it
+    /// starts
+    ReversedInterval,
+    /// The wall-clock time is skipped in the time zone, when daylight
+    /// saving time starts, see [`Disambiguation::Reject`](crate::Disambiguation::Reject)
+    SkippedLocalTime,
+    /// The wall-clock time is repeated in the time zone, when daylight
+    /// saving time ends, see [`Disambiguation::Reject`](crate::Disambiguation::Reject)
+    RepeatedLocalTime,
+    /// The time zone isn't a known IANA identifier, or without the `tzdb`
+    /// feature, a zone whose offsets aren't known
+    UnknownTimeZone(String),
+    /// A formatter couldn't be built
+    Load(LoadError),
+    /// A built formatter failed on the value, for example because the
+    /// locale data lacks a field it needs
+    Format,
+    /// The bundle rejected the registration
+    Fluent(FluentError),
+}
+
+impl fmt::Display for FluentDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOption { name, value } => {
+                write!(f, "invalid value {value:?} for option {name}")
+            }
+            Self::WrongOptionType { name, expected } => {
+                write!(f, "option {name} must be {expected}")
+            }
+            Self::UnknownOption(name) => write!(f, "unknown option {name}"),
+            Self::MissingDateTime => f.write_str("expected a datetime argument"),
+            Self::FloatingTimeZone => f.write_str("floating datetimes take no time zone"),
+            Self::InvalidDateTime(s) => write!(f, "invalid ISO 8601 datetime {s}"),
+            Self::YearOutOfRange(year) => write!(f, "year {year} out of range"),
+            Self::InvalidDuration(s) => write!(f, "invalid ISO 8601 duration {s}"),
+            Self::UnrecognizedDate(s) => write!(f, "can't read {s:?} as a date"),
+            Self::UnknownDirective(d) => write!(f, "unknown strftime directive {d}"),
+            Self::ReversedInterval => f.write_str("interval ends before it starts"),
+            Self::SkippedLocalTime => f.write_str("local time skipped in the time zone"),
+            Self::RepeatedLocalTime => f.write_str("local time repeated in the time zone"),
+            Self::UnknownTimeZone(tz) => write!(f, "unknown time zone {tz}"),
+            Self::Load(e) => write!(f, "can't build formatter: {e}"),
+            Self::Format => f.write_str("formatting failed"),
+            Self::Fluent(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for FluentDateTimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Load(e) => Some(e),
+            Self::Fluent(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<LoadError> for FluentDateTimeError {
+    fn from(e: LoadError) -> Self {
+        Self::Load(e)
+    }
+}
+
+impl From<FluentError> for FluentDateTimeError {
+    fn from(e: FluentError) -> Self {
+        Self::Fluent(e)
+    }
+}
@@ -0,0 +1,68 @@
+//! Hour cycles for formatted times
+
+use icu_datetime::options::preferences;
+use icu_datetime::pattern::CoarseHourCycle;
+use icu_datetime::provider::calendar::TimeLengthsV1Marker;
+use icu_locid::extensions::unicode::{value, Value};
+use icu_provider::{DataLocale, DataRequest};
+
+use crate::provider::{load_data, Provider};
+use crate::LoadError;
+
+/// How hours are numbered, like the `-u-hc` locale extension
+///
+/// Without it, the locale's preferred hour cycle is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HourCycle {
+    /// 0 to 11, with AM/PM
+    H11,
+    /// 1 to 12, with AM/PM
+    H12,
+    /// 0 to 23
+    H23,
+    /// 1 to 24
+    H24,
+}
+
+impl HourCycle {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "h11" => Self::H11,
+            "h12" => Self::H12,
+            "h23" => Self::H23,
+            "h24" => Self::H24,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn to_keyword(self) -> Value {
+        match self {
+            Self::H11 => value!("h11"),
+            Self::H12 => value!("h12"),
+            Self::H23 => value!("h23"),
+            Self::H24 => value!("h24"),
+        }
+    }
+
+    /// The hour cycle of the locale's time patterns
+    pub(crate) fn preferred(provider: Provider, locale: &DataLocale) -> Result<Self, LoadError> {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let lengths = load_data!(provider, TimeLengthsV1Marker, req)?.take_payload()?;
+        Ok(match lengths.get().preferred_hour_cycle {
+            CoarseHourCycle::H11H12 => Self::H12,
+            CoarseHourCycle::H23H24 => Self::H23,
+        })
+    }
+
+    pub(crate) fn to_preference(self) -> preferences::HourCycle {
+        match self {
+            Self::H11 => preferences::HourCycle::H11,
+            Self::H12 => preferences::HourCycle::H12,
+            Self::H23 => preferences::HourCycle::H23,
+            Self::H24 => preferences::HourCycle::H24,
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Ranges between two datetimes, like "Nov 9, 1989, 10:00 AM – 11:30 PM"
+//!
+//! ICU 1.x has no interval patterns.  Ranges within a day write the date
+//! once, when the time is shown, and the two ends are joined with the
+//! CLDR fallback pattern of the language, "{0} – {1}" in most.  For the
+//! same reason, parts of a range are whole ends, with the date of a
+//! same-day range in the start.
+
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use fluent_bundle::types::FluentType;
+use fluent_bundle::{FluentArgs, FluentValue};
+use icu_calendar::Iso;
+
+use crate::{report, resolve_datetime, DateTimeSupport, GimmeTheLocale};
+use crate::{FluentDateTime, FluentDateTimeError, FluentDateTimeOptions};
+
+/// What goes between the two ends of a range in a language
+///
+/// ICU4X 1.x doesn't ship CLDR's `intervalFormatFallback`, so this is an
+/// excerpt of its Gregorian patterns: the languages that differ from root.
+fn separator(lang: &unic_langid::LanguageIdentifier) -> &'static str {
+    match lang.language.as_str() {
+        "en" => "\u{2009}–\u{2009}",
+        "ja" => "～",
+        "ko" => " ~ ",
+        _ => " – ",
+    }
+}
+
+/// A range between two datetimes, formatted with the same options
+///
+/// Applications pass the whole range as one argument, and translators
+/// don't have to stitch two dates together.  The date is written once
+/// when both ends fall on the same day and the time is shown, and
+/// a range whose ends look the same with the options, like two times on
+/// the same day with only a date style, is written as a single date.
+/// [`DATETIME`](crate::DATETIME) takes ranges, with the same options as
+/// datetimes.
+///
+/// ICU 1.x has no interval patterns, so ranges don't collapse the fields
+/// both ends share beyond the date, as in "November 9–12, 1989": the two
+/// formatted ends are joined with the language's CLDR fallback separator.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{datetime, BundleExt, FluentDateInterval};
+/// use icu_datetime::options::length;
+///
+/// let meeting = FluentDateInterval::try_new(datetime!(1989-11-09 10:00), datetime!(1989-11-09 11:30))?
+///     .map_options(|o| {
+///         o.set_date_style(Some(length::Date::Medium));
+///         o.set_time_style(Some(length::Time::Short));
+///     });
+/// assert_eq!(
+///     meeting.format(&"en-US".parse()?).unwrap(),
+///     "Nov 9, 1989, 10:00\u{202f}AM\u{2009}–\u{2009}11:30\u{202f}AM"
+/// );
+/// assert_eq!(meeting.format(&"de-DE".parse()?).unwrap(), "09.11.1989, 10:00 – 11:30");
+/// assert_eq!(meeting.format(&"ja".parse()?).unwrap(), "1989/11/09 10:00～11:30");
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_datetime_support()?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "trip = Trip: {DATETIME($dates, dateStyle: \"long\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let trip = bundle.get_message("trip").unwrap().value().unwrap();
+/// let dates: FluentDateInterval = "1989-11-09/1989-11-12".parse()?;
+/// let args = FluentArgs::from_iter([("dates", dates)]);
+/// assert_eq!(
+///     bundle.format_pattern(trip, Some(&args), &mut vec![]),
+///     "Trip: November 9, 1989\u{2009}–\u{2009}November 12, 1989"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FluentDateInterval {
+    start: FluentDateTime,
+    end: FluentDateTime,
+    /// Options for rendering both ends
+    pub options: FluentDateTimeOptions,
+}
+
+impl FluentDateInterval {
+    /// The range from `start` to `end`, with the options of `start`
+    ///
+    /// Fails if `end` is before `start`.
+    pub fn try_new(
+        start: FluentDateTime,
+        end: FluentDateTime,
+    ) -> Result<Self, FluentDateTimeError> {
+        if end < start {
+            return Err(FluentDateTimeError::ReversedInterval);
+        }
+        Ok(Self {
+            options: start.options.clone(),
+            start,
+            end,
+        })
+    }
+
+    /// The start of the range
+    pub fn start(&self) -> &FluentDateTime {
+        &self.start
+    }
+
+    /// The end of the range
+    pub fn end(&self) -> &FluentDateTime {
+        &self.end
+    }
+
+    /// Changes the formatting options, in a chain
+    ///
+    /// See [`FluentDateTime::map_options`].
+    pub fn map_options(mut self, f: impl FnOnce(&mut FluentDateTimeOptions)) -> Self {
+        f(&mut self.options);
+        self
+    }
+
+    /// Formats with the attached options, for an explicit locale
+    ///
+    /// Fails like [`FluentDateTime::format`].
+    pub fn format(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<String, FluentDateTimeError> {
+        let (start, end) = self.ends();
+        Ok(join(locale, start.format(locale)?, end.format(locale)?))
+    }
+
+    /// Formats for an explicit locale, as the parts of the start, the end
+    /// and what they share, like `formatRangeToParts` of
+    /// `Intl.DateTimeFormat`
+    ///
+    /// This lets interfaces style the ends differently.  Concatenated, the
+    /// parts are the text of [`format`](Self::format).  A range written
+    /// as a single date is one shared part.
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, FluentDateInterval, RangePart, RangeSource};
+    ///
+    /// let stay = FluentDateInterval::try_new(datetime!(1989-11-09 12:00), datetime!(1989-11-12 12:00))?;
+    /// let parts = stay.format_to_parts(&"de-DE".parse()?).unwrap();
+    /// assert_eq!(
+    ///     parts,
+    ///     [
+    ///         RangePart { source: RangeSource::Start, value: "09.11.89".to_string() },
+    ///         RangePart { source: RangeSource::Shared, value: " – ".to_string() },
+    ///         RangePart { source: RangeSource::End, value: "12.11.89".to_string() },
+    ///     ]
+    /// );
+    /// let day = FluentDateInterval::try_new(datetime!(1989-11-09 10:00), datetime!(1989-11-09 12:00))?;
+    /// let parts = day.format_to_parts(&"de-DE".parse()?).unwrap();
+    /// assert_eq!(parts, [RangePart { source: RangeSource::Shared, value: "09.11.89".to_string() }]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_to_parts(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Vec<RangePart>, FluentDateTimeError> {
+        let (start, end) = self.ends();
+        Ok(parts(locale, start.format(locale)?, end.format(locale)?))
+    }
+
+    /// Renders as an ISO 8601 interval, like
+    /// `1989-11-09T10:00:00/1989-11-09T11:30:00`
+    pub fn to_iso8601(&self) -> String {
+        format!("{}/{}", self.start.to_iso8601(), self.end.to_iso8601())
+    }
+
+    /// The two ends, with the options they are formatted with
+    fn ends(&self) -> (FluentDateTime, FluentDateTime) {
+        let start = self
+            .start
+            .clone()
+            .map_options(|o| *o = self.options.clone());
+        let mut end = self.end.clone().map_options(|o| *o = self.options.clone());
+        let same_day = match (start.to_calendar(Iso), end.to_calendar(Iso)) {
+            (Ok(s), Ok(e)) => s.date == e.date,
+            _ => false,
+        };
+        if same_day && self.options.date_style.is_some() && self.options.time_style.is_some() {
+            end.options.date_style = None;
+        }
+        (start, end)
+    }
+}
+
+/// Which end of a range a [`RangePart`] comes from, like the `source` of
+/// `formatRangeToParts` in `Intl.DateTimeFormat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeSource {
+    /// The start, `startRange` in Intl
+    Start,
+    /// The end, `endRange` in Intl
+    End,
+    /// Both, like the separator, `shared` in Intl
+    Shared,
+}
+
+/// A piece of a formatted range, see [`FluentDateInterval::format_to_parts`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangePart {
+    /// Where the text comes from
+    pub source: RangeSource,
+    /// The text
+    pub value: String,
+}
+
+fn parts(
+    lang: &unic_langid::LanguageIdentifier,
+    start: impl Into<String>,
+    end: impl Into<String>,
+) -> Vec<RangePart> {
+    let (start, end) = (start.into(), end.into());
+    let part = |source, value| RangePart { source, value };
+    if start == end {
+        return vec![part(RangeSource::Shared, start)];
+    }
+    vec![
+        part(RangeSource::Start, start),
+        part(RangeSource::Shared, separator(lang).to_string()),
+        part(RangeSource::End, end),
+    ]
+}
+
+fn join(
+    lang: &unic_langid::LanguageIdentifier,
+    start: impl Into<String>,
+    end: impl Into<String>,
+) -> String {
+    parts(lang, start, end)
+        .into_iter()
+        .map(|part| part.value)
+        .collect()
+}
+
+/// Parses an ISO 8601 interval of two datetimes, like
+/// `1989-11-09T10:00/1989-11-09T11:30`, see [`FromStr`] for
+/// [`FluentDateTime`]
+impl FromStr for FluentDateInterval {
+    type Err = FluentDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('/')
+            .ok_or_else(|| FluentDateTimeError::InvalidDateTime(s.to_string()))?;
+        Self::try_new(start.parse()?, end.parse()?)
+    }
+}
+
+impl FluentType for FluentDateInterval {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let (start, end) = self.ends();
+        join(&lang, start.as_string(intls), end.as_string(intls)).into()
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let (start, end) = self.ends();
+        join(
+            &lang,
+            start.as_string_threadsafe(intls),
+            end.as_string_threadsafe(intls),
+        )
+        .into()
+    }
+}
+
+impl From<FluentDateInterval> for FluentValue<'static> {
+    fn from(value: FluentDateInterval) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+/// The range a DATETIME call evaluates to
+///
+/// The options are resolved like those of a datetime, on the start.
+pub(crate) fn with_support<'a>(
+    interval: &FluentDateInterval,
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+    support: &DateTimeSupport,
+) -> FluentValue<'a> {
+    let start = interval
+        .start
+        .clone()
+        .map_options(|o| *o = interval.options.clone());
+    let mut positional = positional.to_vec();
+    positional[0] = start.into();
+    let options = match resolve_datetime(&positional, named, support) {
+        Ok(dt) => dt.options,
+        Err((dt, description)) => {
+            report::report(|| description);
+            match dt {
+                Some(dt) => dt.options,
+                None => return FluentValue::Error,
+            }
+        }
+    };
+    FluentValue::Custom(Box::new(FluentDateInterval {
+        options,
+        ..interval.clone()
+    }))
+}
@@ -0,0 +1,71 @@
+//! Fluent arguments from JSON
+
+use fluent_bundle::{FluentArgs, FluentValue};
+use serde_json::{Map, Value};
+
+use crate::{convert, FluentDateTime, FluentDateTimeError};
+
+/// Converts a JSON object to Fluent arguments, with datetimes for
+/// `datetime_keys`
+///
+/// The values of `datetime_keys` are ISO 8601 strings, see
+/// [`FromStr`](std::str::FromStr) for [`FluentDateTime`], or Unix
+/// timestamps in seconds.  Other strings and numbers are passed as is,
+/// booleans as `"true"` and `"false"`, which Fluent selectors can match;
+/// nulls, arrays and objects are left out.
+///
+/// ```
+/// use fluent_datetime::{args_from_json, as_datetime};
+///
+/// let json = serde_json::json!({
+///     "name": "Ana",
+///     "sent": "1989-11-09T23:30:00Z",
+///     "seen": 626657400,
+/// });
+/// let args = args_from_json(json.as_object().unwrap(), &["sent", "seen"])?;
+/// let sent = as_datetime(args.get("sent").unwrap()).unwrap();
+/// let seen = as_datetime(args.get("seen").unwrap()).unwrap();
+/// assert_eq!((sent.day(), seen.day()), (9, 9));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn args_from_json(
+    object: &Map<String, Value>,
+    datetime_keys: &[&str],
+) -> Result<FluentArgs<'static>, FluentDateTimeError> {
+    let mut args = FluentArgs::new();
+    for (key, value) in object {
+        let value = if datetime_keys.contains(&key.as_str()) {
+            FluentValue::from(datetime_from_json(value)?)
+        } else {
+            match value {
+                Value::String(s) => FluentValue::from(s.clone()),
+                Value::Number(n) => match n.as_f64() {
+                    Some(n) => FluentValue::from(n),
+                    None => continue,
+                },
+                Value::Bool(b) => FluentValue::from(b.to_string()),
+                Value::Null | Value::Array(_) | Value::Object(_) => continue,
+            }
+        };
+        args.set(key.clone(), value);
+    }
+    Ok(args)
+}
+
+fn datetime_from_json(value: &Value) -> Result<FluentDateTime, FluentDateTimeError> {
+    match value {
+        Value::String(s) => s.parse(),
+        Value::Number(n) => {
+            let timestamp = match (n.as_i64(), n.as_f64()) {
+                (Some(secs), _) => convert::from_unix(secs, 0),
+                (None, Some(secs)) if secs.is_finite() && secs.abs() < i64::MAX as f64 => {
+                    let whole = secs.floor();
+                    convert::from_unix(whole as i64, ((secs - whole) * 1e9) as u32)
+                }
+                _ => None,
+            };
+            timestamp.ok_or_else(|| FluentDateTimeError::InvalidDateTime(n.to_string()))
+        }
+        value => Err(FluentDateTimeError::InvalidDateTime(value.to_string())),
+    }
+}
@@ -0,0 +1,131 @@
+//! Bundles for the locales a client asks for
+
+use std::borrow::Borrow;
+
+use fluent_bundle::bundle::FluentBundle;
+use fluent_bundle::memoizer::MemoizerKind;
+use fluent_bundle::FluentResource;
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use unic_langid::LanguageIdentifier;
+
+use crate::{BundleExt, DateTimeSupport, FluentDateTimeError};
+
+/// The available locales matching an `Accept-Language` header, best
+/// first, ending with `default`
+///
+/// ```
+/// use fluent_datetime::negotiate_locales;
+/// use unic_langid::LanguageIdentifier;
+///
+/// let available: Vec<LanguageIdentifier> = vec!["en-US".parse()?, "fr".parse()?, "de".parse()?];
+/// let locales = negotiate_locales("fr-CH, fr;q=0.9, de;q=0.8", &available, &available[0]);
+/// assert_eq!(locales, [available[1].clone(), available[2].clone(), available[0].clone()]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn negotiate_locales(
+    accept_language: &str,
+    available: &[LanguageIdentifier],
+    default: &LanguageIdentifier,
+) -> Vec<LanguageIdentifier> {
+    let requested = fluent_langneg::accepted_languages::parse(accept_language);
+    negotiate_languages(
+        &requested,
+        available,
+        Some(default),
+        NegotiationStrategy::Filtering,
+    )
+    .into_iter()
+    .cloned()
+    .collect()
+}
+
+/// A bundle for an `Accept-Language` header, with [`DATETIME`](crate::DATETIME)
+///
+/// The bundle locales are [negotiated](negotiate_locales), so datetimes
+/// fall back along them; its resources are those `resources` gives for
+/// the best one.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::FluentResource;
+/// use fluent_datetime::{negotiated_bundle, DateTimeSupport};
+///
+/// let available = ["en-US".parse()?, "fr".parse()?];
+/// let bundle = negotiated_bundle(
+///     "fr-FR,fr;q=0.8",
+///     &available,
+///     &available[0],
+///     DateTimeSupport::new(),
+///     |locale| {
+///         let source = match locale.language.as_str() {
+///             "fr" => "hello = Bonjour",
+///             _ => "hello = Hello",
+///         };
+///         [FluentResource::try_new(source.to_string()).unwrap()]
+///     },
+/// )?;
+/// let mut errors = vec![];
+/// let hello = bundle.get_message("hello").unwrap().value().unwrap();
+/// assert_eq!(bundle.format_pattern(hello, None, &mut errors), "Bonjour");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn negotiated_bundle<R, I>(
+    accept_language: &str,
+    available: &[LanguageIdentifier],
+    default: &LanguageIdentifier,
+    support: DateTimeSupport,
+    resources: impl FnOnce(&LanguageIdentifier) -> I,
+) -> Result<fluent_bundle::FluentBundle<R>, FluentDateTimeError>
+where
+    R: Borrow<FluentResource>,
+    I: IntoIterator<Item = R>,
+{
+    let locales = negotiate_locales(accept_language, available, default);
+    fill_bundle(
+        fluent_bundle::FluentBundle::new(locales),
+        support,
+        resources,
+    )
+}
+
+/// A concurrent bundle for an `Accept-Language` header, with
+/// [`DATETIME`](crate::DATETIME)
+///
+/// Like [`negotiated_bundle`].
+pub fn negotiated_bundle_concurrent<R, I>(
+    accept_language: &str,
+    available: &[LanguageIdentifier],
+    default: &LanguageIdentifier,
+    support: DateTimeSupport,
+    resources: impl FnOnce(&LanguageIdentifier) -> I,
+) -> Result<fluent_bundle::concurrent::FluentBundle<R>, FluentDateTimeError>
+where
+    R: Borrow<FluentResource>,
+    I: IntoIterator<Item = R>,
+{
+    let locales = negotiate_locales(accept_language, available, default);
+    fill_bundle(
+        fluent_bundle::concurrent::FluentBundle::new_concurrent(locales),
+        support,
+        resources,
+    )
+}
+
+fn fill_bundle<R, M, I>(
+    mut bundle: FluentBundle<R, M>,
+    support: DateTimeSupport,
+    resources: impl FnOnce(&LanguageIdentifier) -> I,
+) -> Result<FluentBundle<R, M>, FluentDateTimeError>
+where
+    R: Borrow<FluentResource>,
+    M: MemoizerKind,
+    I: IntoIterator<Item = R>,
+{
+    bundle.add_datetime_support_with(support)?;
+    let locale = bundle.locales.first().cloned().unwrap_or_default();
+    for resource in resources(&locale) {
+        if let Err(mut errors) = bundle.add_resource(resource) {
+            return Err(errors.swap_remove(0).into());
+        }
+    }
+    Ok(bundle)
+}
@@ -16,8 +16,7 @@
 //! line is the only one you need.
 //!
 //! ```rust
-//! use fluent::fluent_args;
-//! use fluent_bundle::{FluentBundle, FluentResource};
+//! use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
 //! use fluent_datetime::{BundleExt, FluentDateTime};
 //! use icu_calendar::DateTime;
 //! use icu_datetime::options::length;
@@ -28,7 +27,7 @@
 //! let mut bundle = FluentBundle::new(vec![langid_en]);
 //!
 //! // Register the DATETIME function
-//! bundle.add_datetime_support();
+//! bundle.add_datetime_support()?;
 //!
 //! // Add a FluentResource to the bundle
 //! let ftl_string = r#"
@@ -58,28 +57,28 @@
 //! assert_eq!(
 //!     bundle.format_pattern(
 //!         &bundle.get_message("today-is").unwrap().value().unwrap(),
-//!         Some(&fluent_args!("date" => datetime.clone())), &mut errors),
+//!         Some(&FluentArgs::from_iter([("date", datetime.clone())])), &mut errors),
 //!     "Today is \u{2068}11/9/89\u{2069}"
 //! );
 //!
 //! assert_eq!(
 //!     bundle.format_pattern(
 //!         &bundle.get_message("today-is-fulldate").unwrap().value().unwrap(),
-//!         Some(&fluent_args!("date" => datetime.clone())), &mut errors),
+//!         Some(&FluentArgs::from_iter([("date", datetime.clone())])), &mut errors),
 //!     "Today is \u{2068}Thursday, November 9, 1989\u{2069}"
 //! );
 //!
 //! assert_eq!(
 //!     bundle.format_pattern(
 //!         &bundle.get_message("now-is-time").unwrap().value().unwrap(),
-//!         Some(&fluent_args!("date" => datetime.clone())), &mut errors),
+//!         Some(&FluentArgs::from_iter([("date", datetime.clone())])), &mut errors),
 //!     "Now is \u{2068}11:30:00\u{202f}PM\u{2069}"
 //! );
 //!
 //! assert_eq!(
 //!     bundle.format_pattern(
 //!         &bundle.get_message("now-is-datetime").unwrap().value().unwrap(),
-//!         Some(&fluent_args!("date" => datetime.clone())), &mut errors),
+//!         Some(&FluentArgs::from_iter([("date", datetime.clone())])), &mut errors),
 //!     "Now is \u{2068}Thursday, November 9, 1989, 11:30\u{202f}PM\u{2069}"
 //! );
 //!
@@ -90,26 +89,133 @@
 //! assert_eq!(
 //!     bundle.format_pattern(
 //!         &bundle.get_message("today-is").unwrap().value().unwrap(),
-//!         Some(&fluent_args!("date" => datetime)), &mut errors),
+//!         Some(&FluentArgs::from_iter([("date", datetime)])), &mut errors),
 //!     "Today is \u{2068}Thursday, November 9, 1989\u{2069}"
 //! );
 //!
 //! assert!(errors.is_empty());
 //!
-//! # // I would like to use the ? operator, but Fluent and ICU error types don't implement the std Error trait…
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
-use std::borrow::Cow;
-use std::mem::discriminant;
+
+#[cfg(all(feature = "fluent-bundle-015", feature = "fluent-bundle-016"))]
+compile_error!(
+    "the fluent-bundle-015 and fluent-bundle-016 features are mutually exclusive; \
+     disable default features to build against fluent-bundle 0.16"
+);
+#[cfg(not(any(feature = "fluent-bundle-015", feature = "fluent-bundle-016")))]
+compile_error!("enable either the fluent-bundle-015 or the fluent-bundle-016 feature");
+
+/// The fluent-bundle this crate is built against, 0.15 by default
+///
+/// The `fluent-bundle-015` (default) and `fluent-bundle-016` cargo
+/// features pick the release line; exactly one of them must be enabled.
+/// Bundles and custom values only work with the matching version, which
+/// dependents can name through this re-export rather than in lockstep
+/// with their own fluent-bundle dependency.
+#[cfg(all(feature = "fluent-bundle-015", not(feature = "fluent-bundle-016")))]
+pub extern crate fluent_bundle;
+/// The fluent-bundle this crate is built against, 0.16 with the
+/// `fluent-bundle-016` cargo feature
+#[cfg(feature = "fluent-bundle-016")]
+pub extern crate fluent_bundle_016 as fluent_bundle;
+/// The fluent-syntax of [`fluent_bundle`]
+#[cfg(all(feature = "fluent-bundle-015", not(feature = "fluent-bundle-016")))]
+pub extern crate fluent_syntax;
+/// The fluent-syntax of [`fluent_bundle`]
+#[cfg(feature = "fluent-bundle-016")]
+pub extern crate fluent_syntax_012 as fluent_syntax;
+
+use std::borrow::{Borrow, Cow};
+use std::fmt::{self, Write as _};
+use std::sync::Arc;
 
 use fluent_bundle::bundle::FluentBundle;
+use fluent_bundle::memoizer::MemoizerKind;
 use fluent_bundle::types::FluentType;
-use fluent_bundle::{FluentArgs, FluentError, FluentValue};
+use fluent_bundle::{FluentArgs, FluentError, FluentResource, FluentValue};
+use fluent_syntax::ast;
 
-use icu_calendar::{Gregorian, Iso};
-use icu_datetime::options::length;
+use icu_calendar::{AsCalendar, Date, Gregorian, Iso, Time};
+use icu_casemap::{CaseMapper, TitlecaseMapper};
+use icu_datetime::options::{components, length, preferences};
+use icu_locid::extensions::unicode;
+use icu_timezone::MetazoneCalculator;
+
+mod alignment;
+mod cache;
+mod calendar;
+mod casing;
+mod convert;
+mod datagen;
+mod duration;
+mod duration_data;
+mod error;
+mod hour_cycle;
+mod interval;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "langneg")]
+mod langneg;
+mod lunisolar;
+mod macros;
+mod names;
+mod parse;
+mod partial;
+mod policy;
+mod provider;
+mod pseudo;
+mod relative;
+mod report;
+mod scan;
+mod semantics;
+mod strftime;
+mod supported;
+mod template;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+use provider::{Provider, SharedProvider};
+mod timezone;
+mod validate;
+mod warning;
+mod week;
+mod weekday;
+pub use alignment::Alignment;
+pub use cache::{CacheStats, FormatterCache};
+pub use casing::Casing;
+pub use convert::{as_datetime, IntoFluentDateTime};
+pub use datagen::{all_data_keys, data_keys, data_keys_for_resources};
+pub use duration::{DurationOptions, DurationStyle, DurationUnit, FluentDuration, DURATION};
+pub use error::{FluentDateTimeError, LoadError};
+pub use hour_cycle::HourCycle;
+pub use interval::{FluentDateInterval, RangePart, RangeSource};
+#[cfg(feature = "serde_json")]
+pub use json::args_from_json;
+#[cfg(feature = "langneg")]
+pub use langneg::{negotiate_locales, negotiated_bundle, negotiated_bundle_concurrent};
+#[doc(hidden)]
+pub use macros::__macro;
+pub use names::{DateNames, NameContext, NameWidth};
+pub use parse::DateParser;
+pub use partial::{FluentMonthDay, FluentYearMonth};
+pub use policy::{ArgumentFallback, ErrorPolicy};
+#[cfg(feature = "blob")]
+pub use provider::{load_locale_data_blob, register_data_blob, register_data_blob_file};
+pub use pseudo::Pseudolocalization;
+pub use relative::{Relative, RelativeNumeric, RelativeRounding, RelativeUnit};
+pub use semantics::Semantics;
+pub use strftime::StrftimeConversion;
+pub use supported::{supported_options, OptionInfo, OptionValues};
+pub use template::DateTimeFilter;
+#[cfg(feature = "system-timezone")]
+pub use timezone::system_timezone;
+pub use timezone::{Disambiguation, TimeZone};
+pub use validate::{validate_resource, validate_resource_strict, Diagnostic};
+pub use warning::Warning;
+pub use week::{WeekInfo, WeekNumber};
+pub use weekday::{FluentWeekday, WEEKDAY};
 
 fn val_as_str<'a>(val: &'a FluentValue) -> Option<&'a str> {
     if let FluentValue::String(str) = val {
@@ -119,19 +225,54 @@ fn val_as_str<'a>(val: &'a FluentValue) -> Option<&'a str> {
     }
 }
 
+fn val_as_keyword(val: &FluentValue) -> Option<unicode::Value> {
+    unicode::Value::try_from_bytes(val_as_str(val)?.as_bytes()).ok()
+}
+
+/// The value as written in Fluent, for error messages
+fn val_to_string(val: &FluentValue) -> String {
+    match val {
+        FluentValue::String(str) => str.to_string(),
+        FluentValue::Number(n) => n.as_string().into_owned(),
+        FluentValue::Custom(cus) => format!("{cus:?}"),
+        FluentValue::None | FluentValue::Error => String::new(),
+    }
+}
+
 /// Options for formatting a DateTime
-#[derive(Debug, Clone, PartialEq)]
+// Every field takes part in the derived Hash and Eq, so new options can't
+// be left out of either; formatters are keyed on FormatterKey instead
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FluentDateTimeOptions {
-    // This calendar arg makes loading provider data and memoizing formatters harder
-    // In particular, the AnyCalendarKind logic (in
-    // AnyCalendarKind::from_data_locale_with_fallback) that defaults to
-    // Gregorian for most calendars, except for the thai locale (Buddhist),
-    // isn't exposed.  So we would have to build the formatter and then decide
-    // if it is the correct one for the calendar we want.
-    //calendar: Option<icu_calendar::AnyCalendarKind>,
+    // The output calendar is picked from the locale (-u-ca keyword, or the
+    // locale default, like Buddhist for th or Persian for fa-IR), see calendar::resolve
     // We don't handle icu_datetime per-component settings atm, it is experimental
-    // and length is expressive enough so far
-    length: length::Bag,
+    // and the styles are expressive enough so far, except for week numbers
+    // and eras.
+    // They are kept apart rather than as a length::Bag, which doesn't
+    // implement Hash
+    date_style: Option<length::Date>,
+    time_style: Option<length::Time>,
+    week_number: Option<WeekNumber>,
+    era: Option<NameWidth>,
+    time_zone: Option<TimeZone>,
+    casing: Option<Casing>,
+    bidi_controls: Option<BidiControls>,
+    pseudo: Option<Pseudolocalization>,
+    relative: Option<Relative>,
+    numbering_system: Option<unicode::Value>,
+    calendar: Option<unicode::Value>,
+    hour_cycle: Option<HourCycle>,
+    alignment: Option<Alignment>,
+    error_policy: Option<ErrorPolicy>,
+    // Set by partial dates like FluentYearMonth, see partial::Fields
+    fields: Option<partial::Fields>,
+    // Set by DATETIME in bundles with their own data source
+    provider: Option<SharedProvider>,
+    // The bundle locales, set by DATETIME, tried when data is missing
+    fallbacks: Arc<[unic_langid::LanguageIdentifier]>,
+    // Set by DATETIME in bundles sharing a formatter cache
+    cache: Option<FormatterCache>,
 }
 
 impl Default for FluentDateTimeOptions {
@@ -152,85 +293,706 @@ impl Default for FluentDateTimeOptions {
     /// [Intl.DateTimeFormat]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat
     fn default() -> Self {
         Self {
-            length: length::Bag::empty(),
+            date_style: None,
+            time_style: None,
+            week_number: None,
+            era: None,
+            time_zone: None,
+            casing: None,
+            bidi_controls: None,
+            pseudo: None,
+            relative: None,
+            numbering_system: None,
+            calendar: None,
+            hour_cycle: None,
+            alignment: None,
+            error_policy: None,
+            fields: None,
+            provider: None,
+            fallbacks: Arc::new([]),
+            cache: None,
         }
     }
 }
 
 impl FluentDateTimeOptions {
+    /// Builds options in a chain, starting from the defaults
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTime, FluentDateTimeOptions, HourCycle};
+    /// use icu_calendar::DateTime;
+    /// use icu_datetime::options::length;
+    ///
+    /// let options = FluentDateTimeOptions::builder()
+    ///     .date_style(length::Date::Medium)
+    ///     .time_style(length::Time::Short)
+    ///     .hour_cycle(HourCycle::H23)
+    ///     .build();
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut datetime = FluentDateTime::from(datetime);
+    /// datetime.options = options;
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "Nov 9, 1989, 23:30");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> FluentDateTimeOptionsBuilder {
+        FluentDateTimeOptionsBuilder::default()
+    }
+
     /// Set a date style, from verbose to compact
     ///
     /// See [`icu_datetime::options::length::Date`].
     pub fn set_date_style(&mut self, style: Option<length::Date>) {
-        self.length.date = style;
+        self.date_style = style;
     }
 
     /// Set a time style, from verbose to compact
     ///
+    /// The full and long styles display the
+    /// [time zone](Self::set_time_zone), as in `Pacific Standard Time` and
+    /// `PST`; datetimes without one are displayed in the medium style.
     /// See [`icu_datetime::options::length::Time`].
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, TimeZone};
+    /// use icu_datetime::options::length;
+    ///
+    /// let mut datetime = datetime!(1989-11-09 23:30);
+    /// datetime.options.set_time_style(Some(length::Time::Long));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11:30:00\u{202f}PM");
+    /// datetime.options.set_time_zone(Some(TimeZone::utc()));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11:30:00\u{202f}PM UTC");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn set_time_style(&mut self, style: Option<length::Time>) {
-        self.length.time = style;
+        self.time_style = style;
     }
 
-    fn make_formatter(
-        &self,
-        locale: &icu_provider::DataLocale,
-    ) -> Result<DateTimeFormatter, icu_datetime::DateTimeError> {
-        let mut length = self.length;
-        if length == length::Bag::empty() {
-            length = length::Bag::from_date_style(length::Date::Short);
+    /// Set the time zone to display the datetime in
+    ///
+    /// See [`TimeZone`].
+    pub fn set_time_zone(&mut self, time_zone: Option<TimeZone>) {
+        self.time_zone = time_zone;
+    }
+
+    /// The time zone the datetime is displayed in, if any
+    pub fn time_zone(&self) -> Option<&TimeZone> {
+        self.time_zone.as_ref()
+    }
+
+    /// Set the capitalization context of the datetime
+    ///
+    /// See [`Casing`].
+    pub fn set_casing(&mut self, casing: Option<Casing>) {
+        self.casing = casing;
+    }
+
+    /// Keep or strip the bidi control characters of the formatted output
+    ///
+    /// See [`BidiControls`].
+    pub fn set_bidi_controls(&mut self, bidi_controls: Option<BidiControls>) {
+        self.bidi_controls = bidi_controls;
+    }
+
+    /// Pseudo-localize the formatted output, for QA builds
+    ///
+    /// See [`Pseudolocalization`].
+    pub fn set_pseudo(&mut self, pseudo: Option<Pseudolocalization>) {
+        self.pseudo = pseudo;
+    }
+
+    /// Show datetimes close to a reference time as relative phrases
+    ///
+    /// See [`Relative`].
+    pub fn set_relative(&mut self, relative: Option<Relative>) {
+        self.relative = relative;
+    }
+
+    /// Set the numbering system for digits, like the `-u-nu` locale extension
+    ///
+    /// Fluent bundle locales can't carry Unicode extensions, so this is
+    /// the way to pick Latin digits for `ar-EG`, for example.  Numbering
+    /// systems without locale data are ignored.
+    ///
+    /// ```
+    /// use fluent_datetime::FluentDateTime;
+    /// use icu_calendar::DateTime;
+    /// use icu_locid::extensions::unicode::value;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut datetime = FluentDateTime::from(datetime);
+    /// assert_eq!(datetime.format(&"ar-EG".parse()?).unwrap(), "٩\u{200f}/١١\u{200f}/١٩٨٩");
+    ///
+    /// datetime.options.set_numbering_system(Some(value!("latn")));
+    /// assert_eq!(datetime.format(&"ar-EG".parse()?).unwrap(), "9\u{200f}/11\u{200f}/1989");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_numbering_system(&mut self, numbering_system: Option<unicode::Value>) {
+        self.numbering_system = numbering_system;
+    }
+
+    /// Set the calendar of the output, like the `-u-ca` locale extension
+    ///
+    /// Without it, the locale's default calendar is used, which is
+    /// Gregorian for most locales.  Calendars other than Gregorian need
+    /// their `calendar_*` cargo feature, all enabled by default.
+    ///
+    /// ```
+    /// use fluent_datetime::FluentDateTime;
+    /// use icu_calendar::DateTime;
+    /// use icu_datetime::options::length;
+    /// use icu_locid::extensions::unicode::value;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut datetime = FluentDateTime::from(datetime);
+    /// datetime.options.set_date_style(Some(length::Date::Long));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "November 9, 1989");
+    ///
+    /// datetime.options.set_calendar(Some(value!("japanese")));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "November 9, 1 Heisei");
+    ///
+    /// // Thai defaults to the Buddhist calendar
+    /// datetime.options.set_calendar(None);
+    /// assert_eq!(datetime.format(&"th-TH".parse()?).unwrap(), "9 พฤศจิกายน 2532");
+    /// // and Iran to the Persian one, whatever the language
+    /// assert_eq!(datetime.format(&"fa-IR".parse()?).unwrap(), "۱۸ آبان ۱۳۶۸");
+    /// assert_eq!(datetime.format(&"en-IR".parse()?).unwrap(), "Aban 18, 1368 AP");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// The Chinese and Dangi calendars write leap months with the pattern
+    /// of the locale, and number months as in the calendar, a leap month
+    /// sharing the number of the month before it:
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    /// use icu_datetime::options::length;
+    /// use icu_locid::extensions::unicode::value;
+    ///
+    /// // 2023 has a second second month
+    /// let leap = datetime!(2023-03-22 12:00).map_options(|o| {
+    ///     o.set_calendar(Some(value!("chinese")));
+    ///     o.set_date_style(Some(length::Date::Long));
+    /// });
+    /// assert_eq!(leap.format(&"en-US".parse()?).unwrap(), "Second Monthbis 1, 2023(癸卯)");
+    /// assert_eq!(leap.format(&"zh-CN".parse()?).unwrap(), "2023癸卯年闰二月1");
+    ///
+    /// let after = datetime!(2023-05-22 12:00).map_options(|o| {
+    ///     o.set_calendar(Some(value!("dangi")));
+    ///     o.set_date_style(Some(length::Date::Short));
+    /// });
+    /// assert_eq!(after.format(&"ko-KR".parse()?).unwrap(), "4356. 4. 3.");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_calendar(&mut self, calendar: Option<unicode::Value>) {
+        self.calendar = calendar;
+    }
+
+    /// Set whether to format the week of the year instead of the date
+    ///
+    /// See [`WeekNumber`].  The date and time styles are then ignored.
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, WeekNumber};
+    ///
+    /// // A Sunday, in the first week of 2023 in the US, and the last of
+    /// // 2022 in Germany, where weeks start on Monday
+    /// let mut datetime = datetime!(2023-01-01 12:00);
+    /// datetime.options.set_week_number(Some(WeekNumber::WithYear));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "week 1 of 2023");
+    /// assert_eq!(datetime.format(&"de-DE".parse()?).unwrap(), "Woche 52 des Jahres 2022");
+    ///
+    /// datetime.options.set_week_number(Some(WeekNumber::Numeric));
+    /// assert_eq!(datetime.format(&"de-DE".parse()?).unwrap(), "52");
+    ///
+    /// datetime.options.set_week_number(Some(WeekNumber::IsoWeekDate));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "2022-W52-7");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_week_number(&mut self, week_number: Option<WeekNumber>) {
+        self.week_number = week_number;
+    }
+
+    /// Set whether to write the era of dates, like "AD" or "Heisei"
+    ///
+    /// Without it, dates only show an era where the locale's patterns
+    /// have one, as in Japanese or Persian dates in English, or outside of
+    /// years 1 to 9999, see [`FluentDateTime::YEARS`].  Years of the
+    /// Hebrew calendar are counted Anno Mundi, from the creation:
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, NameWidth};
+    /// use icu_datetime::options::length;
+    /// use icu_locid::extensions::unicode::value;
+    ///
+    /// let hebrew = |datetime: fluent_datetime::FluentDateTime| {
+    ///     datetime.map_options(|o| {
+    ///         o.set_calendar(Some(value!("hebrew")));
+    ///         o.set_date_style(Some(length::Date::Long));
+    ///         o.set_era(Some(NameWidth::Short));
+    ///     })
+    /// };
+    /// let en = "en-US".parse()?;
+    /// // The year changes at Rosh Hashanah, in September
+    /// assert_eq!(hebrew(datetime!(2023-09-15 12:00)).format(&en).unwrap(), "29 Elul 5783 AM");
+    /// assert_eq!(hebrew(datetime!(2023-09-16 12:00)).format(&en).unwrap(), "1 Tishri 5784 AM");
+    /// // 5784 is a leap year, with two months of Adar, and 5783 is not
+    /// assert_eq!(hebrew(datetime!(2023-02-22 12:00)).format(&en).unwrap(), "1 Adar 5783 AM");
+    /// assert_eq!(hebrew(datetime!(2024-02-10 12:00)).format(&en).unwrap(), "1 Adar I 5784 AM");
+    /// assert_eq!(hebrew(datetime!(2024-03-11 12:00)).format(&en).unwrap(), "1 Adar II 5784 AM");
+    /// assert_eq!(
+    ///     hebrew(datetime!(2024-03-11 12:00)).format(&"he-IL".parse()?).unwrap(),
+    ///     "1 באדר ב׳ 5784 AM"
+    /// );
+    ///
+    /// let mut datetime = datetime!(1989-11-09 23:30);
+    /// datetime.options.set_era(Some(NameWidth::Long));
+    /// assert_eq!(datetime.format(&en).unwrap(), "11/9/1989 Anno Domini");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_era(&mut self, era: Option<NameWidth>) {
+        self.era = era;
+    }
+
+    /// Set the hour cycle, like the `-u-hc` locale extension
+    ///
+    /// See [`HourCycle`].
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTime, HourCycle};
+    /// use icu_calendar::DateTime;
+    /// use icu_datetime::options::length;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut datetime = FluentDateTime::from(datetime);
+    /// datetime.options.set_time_style(Some(length::Time::Short));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11:30\u{202f}PM");
+    ///
+    /// datetime.options.set_hour_cycle(Some(HourCycle::H23));
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "23:30");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_hour_cycle(&mut self, hour_cycle: Option<HourCycle>) {
+        self.hour_cycle = hour_cycle;
+    }
+
+    /// Line up datetimes in table columns and log views
+    ///
+    /// See [`Alignment`].
+    pub fn set_alignment(&mut self, alignment: Option<Alignment>) {
+        self.alignment = alignment;
+    }
+
+    /// Set what to render if the datetime can't be formatted
+    ///
+    /// See [`ErrorPolicy`].
+    ///
+    /// ```
+    /// use fluent_datetime::{ErrorPolicy, FluentDateTime, Semantics, TimeZone};
+    /// use fluent_datetime::fluent_bundle::types::FluentType;
+    /// use icu_calendar::DateTime;
+    /// use intl_memoizer::IntlLangMemoizer;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// // Floating datetimes take no time zone
+    /// let mut datetime = FluentDateTime::from(datetime).with_semantics(Semantics::Floating);
+    /// datetime.options.set_time_zone(Some(TimeZone::utc()));
+    /// datetime.options.set_error_policy(Some(ErrorPolicy::Marker));
+    ///
+    /// let intls = IntlLangMemoizer::new("en-US".parse()?);
+    /// assert_eq!(datetime.as_string(&intls), "{DATETIME()}");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_error_policy(&mut self, error_policy: Option<ErrorPolicy>) {
+        self.error_policy = error_policy;
+    }
+
+    /// The options for formatting `value`: dates outside of years 1 to
+    /// 9999 show their era and full year, unless the era is set
+    fn for_value(&self, value: &FluentDateTime) -> Cow<'_, Self> {
+        let shows_date =
+            self.fields.is_some() || self.date_style.is_some() || self.time_style.is_none();
+        if shows_date
+            && self.era.is_none()
+            && self.week_number.is_none()
+            && self.fields != Some(partial::Fields::MonthDay)
+            && !(1..=9999).contains(&value.year())
+        {
+            let mut options = self.clone();
+            options.era = Some(NameWidth::Short);
+            return Cow::Owned(options);
         }
-        Ok(DateTimeFormatter(icu_datetime::DateTimeFormatter::try_new(
-            locale,
-            length.into(),
-        )?))
+        Cow::Borrowed(self)
+    }
+
+    /// The bundle's data source if any, else the default one
+    fn provider(&self) -> Option<Provider<'_>> {
+        match &self.provider {
+            Some(provider) => Some(provider.get()),
+            None => provider::default_provider(),
+        }
+    }
+
+    /// What building a formatter reads from the options
+    pub(crate) fn formatter_key(&self) -> FormatterKey {
+        FormatterKey {
+            date_style: self.date_style,
+            time_style: self.time_style,
+            week_number: self.week_number,
+            era: self.era,
+            zoned: self.time_zone.is_some(),
+            titlecase: self.casing.is_some_and(Casing::needs_titlecase),
+            relative: self.relative.as_ref().map(Relative::formatters_key),
+            numbering_system: self.numbering_system.clone(),
+            // Month-days are always Gregorian, see FluentMonthDay
+            calendar: match self.fields {
+                Some(partial::Fields::MonthDay) => Some(unicode::value!("gregory")),
+                _ => self.calendar.clone(),
+            },
+            hour_cycle: self.hour_cycle,
+            column: self.alignment.unwrap_or_default().is_column(),
+            fields: self.fields,
+            provider: self.provider.clone(),
+            fallbacks: self.fallbacks.clone(),
+        }
+    }
+
+    /// Options given to [`DATETIME`] as named arguments, checked
+    ///
+    /// Use this to validate options ahead of time, for example those of a
+    /// translation being imported.  Unknown options are ignored, like
+    /// [`DATETIME`] does.
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::FluentArgs;
+    /// use fluent_datetime::{FluentDateTimeError, FluentDateTimeOptions};
+    ///
+    /// let mut args = FluentArgs::new();
+    /// args.set("dateStyle", "fulll");
+    /// assert_eq!(
+    ///     FluentDateTimeOptions::from_args(&args).unwrap_err(),
+    ///     FluentDateTimeError::InvalidOption {
+    ///         name: "dateStyle".to_string(),
+    ///         value: "fulll".to_string(),
+    ///     }
+    /// );
+    ///
+    /// args.set("dateStyle", 3);
+    /// assert_eq!(
+    ///     FluentDateTimeOptions::from_args(&args).unwrap_err().to_string(),
+    ///     "option dateStyle must be a string"
+    /// );
+    /// ```
+    pub fn from_args(args: &FluentArgs) -> Result<Self, FluentDateTimeError> {
+        let mut options = Self::default();
+        options.merge_args(args)?;
+        Ok(options)
+    }
+
+    /// Fills in the options that are unset from `defaults`
+    ///
+    /// The date and time styles and the week number go together: defaults
+    /// only apply if they are all unset.
+    fn fill_from(&mut self, defaults: &Self) {
+        if self.date_style.is_none() && self.time_style.is_none() && self.week_number.is_none() {
+            self.date_style = defaults.date_style;
+            self.time_style = defaults.time_style;
+            self.week_number = defaults.week_number;
+        }
+        macro_rules! fill {
+            ($($field:ident),*) => {
+                $(
+                    if self.$field.is_none() {
+                        self.$field = defaults.$field.clone();
+                    }
+                )*
+            };
+        }
+        fill!(
+            era,
+            time_zone,
+            casing,
+            bidi_controls,
+            pseudo,
+            relative,
+            numbering_system,
+            calendar,
+            hour_cycle,
+            alignment,
+            error_policy
+        );
     }
 
-    fn merge_args(&mut self, other: &FluentArgs) -> Result<(), ()> {
-        // TODO set an err state on self to match fluent-js behaviour
+    /// Applies named arguments, with the same options and values as
+    /// [`DATETIME`]
+    ///
+    /// Like fluent-js, invalid options are skipped, keeping the previous
+    /// value, and the others still apply.  Returns the first error.
+    /// Unknown options are ignored.
+    ///
+    /// Custom Fluent functions can use this to take datetime options:
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentValue};
+    /// use fluent_datetime::{as_datetime, FluentDateTimeOptions};
+    ///
+    /// #[allow(non_snake_case)]
+    /// fn CALENDAR_EVENT<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    ///     let Some(start) = positional.first().and_then(as_datetime) else {
+    ///         return FluentValue::Error;
+    ///     };
+    ///     let mut start = start.clone();
+    ///     if start.options.merge_args(named).is_err() {
+    ///         return FluentValue::Error;
+    ///     }
+    ///     start.into()
+    /// }
+    /// ```
+    pub fn merge_args(&mut self, other: &FluentArgs) -> Result<(), FluentDateTimeError> {
+        let mut relative_args = relative::RelativeArgs::default();
+        let mut result = Ok(());
         for (k, v) in other.iter() {
-            match k {
-                "dateStyle" => {
-                    self.length.date = Some(match val_as_str(v).ok_or(())? {
-                        "full" => length::Date::Full,
-                        "long" => length::Date::Long,
-                        "medium" => length::Date::Medium,
-                        "short" => length::Date::Short,
-                        _ => return Err(()),
-                    });
+            if let Err(e) = self.merge_arg(k, v, &mut relative_args) {
+                result = result.and(Err(e));
+            }
+        }
+        if let Some(relative) = &mut self.relative {
+            relative_args.apply(relative);
+        }
+        result
+    }
+
+    fn merge_arg(
+        &mut self,
+        k: &str,
+        v: &FluentValue,
+        relative_args: &mut relative::RelativeArgs,
+    ) -> Result<(), FluentDateTimeError> {
+        // New options also need listing in supported.rs, for tooling
+        let as_str = || {
+            val_as_str(v).ok_or_else(|| FluentDateTimeError::WrongOptionType {
+                name: k.to_string(),
+                expected: "a string",
+            })
+        };
+        let invalid = || FluentDateTimeError::InvalidOption {
+            name: k.to_string(),
+            value: val_to_string(v),
+        };
+        // Month-days never show a year
+        if self.fields == Some(partial::Fields::MonthDay) && matches!(k, "era" | "weekNumber") {
+            return Err(invalid());
+        }
+        match k {
+            "dateStyle" => {
+                self.date_style = Some(match as_str()? {
+                    "full" => length::Date::Full,
+                    "long" => length::Date::Long,
+                    "medium" => length::Date::Medium,
+                    "short" => length::Date::Short,
+                    _ => return Err(invalid()),
+                });
+            }
+            "timeStyle" => {
+                self.time_style = Some(match as_str()? {
+                    "full" => length::Time::Full,
+                    "long" => length::Time::Long,
+                    "medium" => length::Time::Medium,
+                    "short" => length::Time::Short,
+                    _ => return Err(invalid()),
+                });
+            }
+            "casing" => {
+                self.casing = Some(match as_str()? {
+                    "middle-of-sentence" => Casing::MiddleOfSentence,
+                    "beginning-of-sentence" => Casing::BeginningOfSentence,
+                    "standalone" => Casing::Standalone,
+                    _ => return Err(invalid()),
+                });
+            }
+            "timeZone" => {
+                let tz = as_str()?;
+                self.time_zone = Some(
+                    TimeZone::from_iana(tz)
+                        .ok_or_else(|| FluentDateTimeError::UnknownTimeZone(tz.to_string()))?,
+                );
+            }
+            "utcOffset" => {
+                let time_zone = match v {
+                    FluentValue::String(s) => {
+                        TimeZone::parse_offset(s).and_then(TimeZone::from_offset_seconds)
+                    }
+                    FluentValue::Number(n) => TimeZone::from_offset_minutes(n.value),
+                    _ => {
+                        return Err(FluentDateTimeError::WrongOptionType {
+                            name: k.to_string(),
+                            expected: "a string or a number",
+                        })
+                    }
+                };
+                self.time_zone = Some(time_zone.ok_or_else(invalid)?);
+            }
+            "numberingSystem" => {
+                as_str()?;
+                self.numbering_system = Some(val_as_keyword(v).ok_or_else(invalid)?);
+            }
+            "calendar" => {
+                as_str()?;
+                self.calendar = Some(val_as_keyword(v).ok_or_else(invalid)?);
+            }
+            "hourCycle" => {
+                self.hour_cycle = Some(HourCycle::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "alignment" => {
+                self.alignment = Some(Alignment::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "weekNumber" => {
+                self.week_number = Some(WeekNumber::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "era" => {
+                self.era = Some(NameWidth::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "relative" => match as_str()? {
+                "auto" => {
+                    self.relative.get_or_insert_with(Relative::auto);
                 }
-                "timeStyle" => {
-                    self.length.time = Some(match val_as_str(v).ok_or(())? {
-                        "full" => length::Time::Full,
-                        "long" => length::Time::Long,
-                        "medium" => length::Time::Medium,
-                        "short" => length::Time::Short,
-                        _ => return Err(()),
+                _ => return Err(invalid()),
+            },
+            "relativeThreshold" | "relativeNowCutoff" => {
+                let FluentValue::Number(n) = v else {
+                    return Err(FluentDateTimeError::WrongOptionType {
+                        name: k.to_string(),
+                        expected: "a number",
                     });
+                };
+                let secs =
+                    std::time::Duration::try_from_secs_f64(n.value).map_err(|_| invalid())?;
+                if k == "relativeThreshold" {
+                    relative_args.threshold = Some(secs);
+                } else {
+                    relative_args.now_cutoff = Some(secs);
                 }
-                _ => (), // Ignore with no warning
             }
+            "relativeUnit" => {
+                relative_args.unit =
+                    Some(RelativeUnit::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "relativeNumeric" => {
+                relative_args.numeric =
+                    Some(RelativeNumeric::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            "relativeRounding" => {
+                relative_args.rounding =
+                    Some(RelativeRounding::from_keyword(as_str()?).ok_or_else(invalid)?);
+            }
+            _ => (), // Ignore with no warning
         }
         Ok(())
     }
 }
 
-impl std::hash::Hash for FluentDateTimeOptions {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        // We could also use serde… or send a simple PR to have derive(Hash) upstream
-        //self.calendar.hash(state);
-        self.length.date.map(|e| discriminant(&e)).hash(state);
-        self.length.time.map(|e| discriminant(&e)).hash(state);
-    }
+/// Builds [`FluentDateTimeOptions`], see [`FluentDateTimeOptions::builder`]
+#[derive(Debug, Clone, Default)]
+pub struct FluentDateTimeOptionsBuilder {
+    options: FluentDateTimeOptions,
 }
 
-impl Eq for FluentDateTimeOptions {}
+impl FluentDateTimeOptionsBuilder {
+    /// Date style, see [`FluentDateTimeOptions::set_date_style`]
+    pub fn date_style(mut self, date_style: length::Date) -> Self {
+        self.options.date_style = Some(date_style);
+        self
+    }
+
+    /// Time style, see [`FluentDateTimeOptions::set_time_style`]
+    pub fn time_style(mut self, time_style: length::Time) -> Self {
+        self.options.time_style = Some(time_style);
+        self
+    }
+
+    /// Time zone, see [`FluentDateTimeOptions::set_time_zone`]
+    pub fn time_zone(mut self, time_zone: TimeZone) -> Self {
+        self.options.time_zone = Some(time_zone);
+        self
+    }
+
+    /// Capitalization, see [`FluentDateTimeOptions::set_casing`]
+    pub fn casing(mut self, casing: Casing) -> Self {
+        self.options.casing = Some(casing);
+        self
+    }
+
+    /// Bidi controls, see [`FluentDateTimeOptions::set_bidi_controls`]
+    pub fn bidi_controls(mut self, bidi_controls: BidiControls) -> Self {
+        self.options.bidi_controls = Some(bidi_controls);
+        self
+    }
+
+    /// Pseudo-localization, see [`FluentDateTimeOptions::set_pseudo`]
+    pub fn pseudo(mut self, pseudo: Pseudolocalization) -> Self {
+        self.options.pseudo = Some(pseudo);
+        self
+    }
+
+    /// Relative phrases, see [`FluentDateTimeOptions::set_relative`]
+    pub fn relative(mut self, relative: Relative) -> Self {
+        self.options.relative = Some(relative);
+        self
+    }
+
+    /// Numbering system, see [`FluentDateTimeOptions::set_numbering_system`]
+    pub fn numbering_system(mut self, numbering_system: unicode::Value) -> Self {
+        self.options.numbering_system = Some(numbering_system);
+        self
+    }
+
+    /// Output calendar, see [`FluentDateTimeOptions::set_calendar`]
+    pub fn calendar(mut self, calendar: unicode::Value) -> Self {
+        self.options.calendar = Some(calendar);
+        self
+    }
+
+    /// Week number, see [`FluentDateTimeOptions::set_week_number`]
+    pub fn week_number(mut self, week_number: WeekNumber) -> Self {
+        self.options.week_number = Some(week_number);
+        self
+    }
+
+    /// Era, see [`FluentDateTimeOptions::set_era`]
+    pub fn era(mut self, era: NameWidth) -> Self {
+        self.options.era = Some(era);
+        self
+    }
+
+    /// Hour cycle, see [`FluentDateTimeOptions::set_hour_cycle`]
+    pub fn hour_cycle(mut self, hour_cycle: HourCycle) -> Self {
+        self.options.hour_cycle = Some(hour_cycle);
+        self
+    }
+
+    /// Alignment, see [`FluentDateTimeOptions::set_alignment`]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.options.alignment = Some(alignment);
+        self
+    }
+
+    /// Error policy, see [`FluentDateTimeOptions::set_error_policy`]
+    pub fn error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.options.error_policy = Some(error_policy);
+        self
+    }
+
+    /// The options
+    pub fn build(self) -> FluentDateTimeOptions {
+        self.options
+    }
+}
 
 /// An ICU [`DateTime`](icu_calendar::DateTime) with attached formatting options
 ///
 /// Construct from an [`icu_calendar::DateTime`] using From / Into.
 ///
-/// Convert to a [`FluentValue`] with From / Into.
+/// Convert to a [`FluentValue`] with From / Into.  Clones share their heap data,
+/// so [`DATETIME`] and Fluent's own copies stay cheap.
 ///
 /// See [`FluentDateTimeOptions`] and [`FluentDateTimeOptions::default`].
 ///
@@ -242,105 +1004,1435 @@ impl Eq for FluentDateTimeOptions {}
 ///     .expect("Failed to create ICU DateTime");
 ///
 /// let datetime = FluentDateTime::from(datetime);
-// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// assert_eq!(datetime.year(), 1989);
+/// assert_eq!((datetime.month(), datetime.day()), (11, 9));
+/// assert_eq!((datetime.hour(), datetime.minute()), (23, 30));
+/// ```
+///
+/// If formatting fails, the datetime is rendered as ISO 8601:
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::types::FluentType;
+/// use fluent_datetime::{FluentDateTime, Semantics, TimeZone};
+/// use icu_calendar::DateTime;
+/// use intl_memoizer::IntlLangMemoizer;
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// // Floating datetimes take no time zone
+/// let mut datetime = FluentDateTime::from(datetime).with_semantics(Semantics::Floating);
+/// datetime.options.set_time_zone(Some(TimeZone::utc()));
+///
+/// let intls = IntlLangMemoizer::new("en-US".parse()?);
+/// assert_eq!(datetime.as_string(&intls), "1989-11-09T23:30:00Z");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Datetimes compare, hash and sort as instants, whatever their options:
+///
+/// ```
+/// use fluent_datetime::FluentDateTime;
+/// use icu_calendar::DateTime;
+/// use icu_datetime::options::length;
+///
+/// let later = DateTime::try_new_iso_datetime(1989, 11, 10, 0, 0, 0).unwrap();
+/// let earlier = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut dates = vec![FluentDateTime::from(later), FluentDateTime::from(earlier.clone())];
+/// dates.sort();
+/// assert_eq!(dates[0].day(), 9);
+///
+/// let long = FluentDateTime::from(earlier)
+///     .map_options(|o| o.set_date_style(Some(length::Date::Long)));
+/// assert_eq!(dates[0], long);
+///
+/// let unique: std::collections::HashSet<_> = dates.into_iter().chain([long]).collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
 pub struct FluentDateTime {
     // Iso seemed like a natural default, but [AnyCalendarKind::from_data_locale_with_fallback]
     // loads Gregorian in almost all cases.  Differences have to do with eras:
     // proleptic Gregorian has BCE / CE and no year zero, iso has just the one era and a year zero
     value: icu_calendar::DateTime<Gregorian>,
+    semantics: Semantics,
     /// Options for rendering
     pub options: FluentDateTimeOptions,
 }
 
-impl FluentType for FluentDateTime {
-    fn duplicate(&self) -> Box<dyn FluentType + Send> {
-        // Basically Clone
-        Box::new(self.clone())
+impl FluentDateTime {
+    /// The years datetimes can have, numbered like ISO 8601
+    ///
+    /// Parsing, conversions and date arithmetic fail outside of this
+    /// range.  All calendars format dates within it, except the Chinese,
+    /// Korean and observational Islamic calendars (`chinese`, `dangi`,
+    /// `islamic` and `islamic-umalqura`), whose astronomical computations
+    /// only hold from -9999 to 9999.  Beyond a calendar's range,
+    /// formatting fails as for missing data, and Fluent reports it and
+    /// renders ISO 8601.
+    ///
+    /// Dates outside of years 1 to 9999 show their era and full year,
+    /// unless [`set_era`](FluentDateTimeOptions::set_era) says otherwise:
+    /// Gregorian years before 1 would read as years of the common era.
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTime, FluentDateTimeError};
+    /// use icu_datetime::options::length;
+    /// use icu_locid::extensions::unicode::value;
+    ///
+    /// let en = "en-US".parse()?;
+    /// let ides: FluentDateTime = "-0043-03-15".parse()?;
+    /// let ides = ides.map_options(|o| o.set_date_style(Some(length::Date::Long)));
+    /// assert_eq!(ides.format(&en).unwrap(), "March 15, 44 BC");
+    /// assert_eq!(ides.format(&"fr-FR".parse()?).unwrap(), "15 mars 44 av. J.-C.");
+    ///
+    /// let far: FluentDateTime = "+275760-09-13".parse()?;
+    /// assert_eq!(far.format(&en).unwrap(), "9/13/275760 AD");
+    /// let far = far.map_options(|o| o.set_calendar(Some(value!("chinese"))));
+    /// assert_eq!(far.format(&en), Err(FluentDateTimeError::Format));
+    /// assert_eq!(far.display(&en).to_string(), "+275760-09-13T00:00:00");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const YEARS: std::ops::RangeInclusive<i32> = -999_999..=999_999;
+
+    /// The wrapped datetime
+    pub fn value(&self) -> &icu_calendar::DateTime<Gregorian> {
+        &self.value
     }
 
-    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
-        intls
-            .with_try_get::<DateTimeFormatter, _, _>(self.options.clone(), |dtf| {
-                dtf.0
-                    .format_to_string(&self.value.to_any())
-                    .unwrap_or_default()
-            })
-            .unwrap_or_default()
-            .into()
+    /// Unwraps the datetime, discarding formatting options
+    pub fn into_inner(self) -> icu_calendar::DateTime<Gregorian> {
+        self.value
     }
 
-    fn as_string_threadsafe(
-        &self,
-        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
-    ) -> Cow<'static, str> {
-        // Maybe don't try to cache formatters in this case, the traits don't work out
-        let lang = intls
-            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
-            .expect("Infallible");
-        let Some(langid): Option<icu_locid::LanguageIdentifier> = lang.to_string().parse().ok()
-        else {
-            return "".into();
-        };
-        let Ok(dtf) = self.options.make_formatter(&langid.into()) else {
-            return "".into();
-        };
-        dtf.0
-            .format_to_string(&self.value.to_any())
-            .unwrap_or_default()
-            .into()
+    /// The formatting options, for changing them in place
+    pub fn options_mut(&mut self) -> &mut FluentDateTimeOptions {
+        &mut self.options
     }
-}
 
-impl From<icu_calendar::DateTime<Gregorian>> for FluentDateTime {
-    fn from(value: icu_calendar::DateTime<Gregorian>) -> Self {
-        Self {
-            value,
-            options: Default::default(),
-        }
+    /// Changes the formatting options, in a chain
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentValue};
+    /// use fluent_datetime::FluentDateTime;
+    /// use icu_calendar::DateTime;
+    /// use icu_datetime::options::length;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let args = FluentArgs::from_iter([(
+    ///     "date",
+    ///     FluentDateTime::from(datetime)
+    ///         .map_options(|o| o.set_date_style(Some(length::Date::Long))),
+    /// )]);
+    /// let FluentValue::Custom(date) = args.get("date").unwrap() else { unreachable!() };
+    /// let date = date.as_any().downcast_ref::<FluentDateTime>().unwrap();
+    /// assert_eq!(date.format(&"en-US".parse()?).unwrap(), "November 9, 1989");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn map_options(mut self, f: impl FnOnce(&mut FluentDateTimeOptions)) -> Self {
+        f(&mut self.options);
+        self
     }
-}
 
-impl From<icu_calendar::DateTime<Iso>> for FluentDateTime {
-    fn from(value: icu_calendar::DateTime<Iso>) -> Self {
-        Self {
-            value: value.to_calendar(Gregorian),
-            options: Default::default(),
+    /// Reads a wall-clock datetime as the local time of a time zone
+    ///
+    /// The datetime is displayed in that zone, like with
+    /// [`with_utc_offset`](Self::with_utc_offset), and its fields become
+    /// those of UTC.  `disambiguation` settles times that the zone skips
+    /// or repeats.  Zones other than UTC offsets and `Etc` zones need the
+    /// `tzdb` feature.  Floating datetimes take no time zone.
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, Disambiguation, TimeZone};
+    ///
+    /// let tz = TimeZone::from_iana("Etc/GMT-2").unwrap();
+    /// let datetime = datetime!(1989-11-09 23:30).in_time_zone(tz, Disambiguation::Reject)?;
+    /// assert_eq!(datetime.hour(), 21);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// ```
+    /// # #[cfg(feature = "tzdb")] {
+    /// use fluent_datetime::{datetime, Disambiguation, FluentDateTimeError, TimeZone};
+    ///
+    /// // Clocks went from 2:00 to 3:00 in Paris that night
+    /// let paris = TimeZone::from_iana("Europe/Paris").unwrap();
+    /// let skipped = datetime!(2024-03-31 02:30);
+    /// assert_eq!(
+    ///     skipped.clone().in_time_zone(paris.clone(), Disambiguation::Reject),
+    ///     Err(FluentDateTimeError::SkippedLocalTime)
+    /// );
+    /// let later = skipped.in_time_zone(paris, Disambiguation::Later).unwrap();
+    /// assert_eq!((later.hour(), later.minute()), (1, 30));
+    /// # }
+    /// ```
+    pub fn in_time_zone(
+        mut self,
+        time_zone: TimeZone,
+        disambiguation: Disambiguation,
+    ) -> Result<Self, FluentDateTimeError> {
+        if self.semantics == Semantics::Floating {
+            return Err(FluentDateTimeError::FloatingTimeZone);
         }
+        self.value = time_zone.to_utc(&self.value, disambiguation)?;
+        self.options.time_zone = Some(time_zone);
+        Ok(self)
     }
-}
 
-impl From<FluentDateTime> for FluentValue<'static> {
-    fn from(value: FluentDateTime) -> Self {
-        Self::Custom(Box::new(value))
+    /// Whether the datetime is an instant or a wall-clock time
+    pub fn semantics(&self) -> Semantics {
+        self.semantics
     }
-}
 
-struct DateTimeFormatter(icu_datetime::DateTimeFormatter);
+    /// Sets whether the datetime is an instant or a wall-clock time, in
+    /// a chain
+    ///
+    /// See [`Semantics`].
+    pub fn with_semantics(mut self, semantics: Semantics) -> Self {
+        self.semantics = semantics;
+        self
+    }
 
-impl intl_memoizer::Memoizable for DateTimeFormatter {
-    type Args = FluentDateTimeOptions;
+    /// Attaches a UTC offset, in seconds east of UTC, to a wall-clock
+    /// datetime
+    ///
+    /// The datetime keeps its wall-clock time, and time styles that show
+    /// a zone display the offset.  Its fields become those of UTC, like
+    /// with any [time zone](FluentDateTimeOptions::set_time_zone).  Returns
+    /// `None` beyond ±18 hours, and for [floating](Semantics::Floating)
+    /// datetimes.
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    /// use icu_datetime::options::length;
+    ///
+    /// let datetime = datetime!(1989-11-09 23:30)
+    ///     .with_utc_offset(3600)
+    ///     .unwrap()
+    ///     .map_options(|o| o.set_time_style(Some(length::Time::Long)));
+    /// assert_eq!(datetime.hour(), 22);
+    /// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11:30:00\u{202f}PM GMT+01:00");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_utc_offset(mut self, offset: i32) -> Option<Self> {
+        if self.semantics == Semantics::Floating {
+            return None;
+        }
+        let time_zone = TimeZone::from_offset_seconds(offset)?;
+        self.value = convert::shift_seconds(&self.value, -i64::from(offset))?;
+        self.options.time_zone = Some(time_zone);
+        Some(self)
+    }
 
-    type Error = ();
+    /// Adds a number of days, or subtracts them if negative
+    ///
+    /// Days are counted on the wall clock: with a time zone, the time of
+    /// day stays the same across daylight saving time changes, unless it
+    /// is skipped, see [`Disambiguation::Compatible`].  Returns `None` out
+    /// of range.
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, Disambiguation, TimeZone};
+    ///
+    /// let datetime = datetime!(1989-11-09 23:30).add_days(-10).unwrap();
+    /// assert_eq!((datetime.month(), datetime.day()), (10, 30));
+    ///
+    /// # #[cfg(feature = "tzdb")] {
+    /// // The day before Paris switches to winter time, at 21:30 UTC
+    /// let paris = TimeZone::from_iana("Europe/Paris").unwrap();
+    /// let datetime = datetime!(1989-09-23 23:30).in_time_zone(paris, Disambiguation::Reject).unwrap();
+    /// assert_eq!(datetime.hour(), 21);
+    /// assert_eq!(datetime.add_days(1).unwrap().hour(), 22);
+    /// # }
+    /// ```
+    pub fn add_days(self, days: i32) -> Option<Self> {
+        self.shift_local(|local| convert::shift_seconds(local, i64::from(days) * 86_400))
+    }
 
-    fn construct(
-        lang: unic_langid::LanguageIdentifier,
-        args: Self::Args,
-    ) -> Result<Self, Self::Error>
-    where
-        Self: std::marker::Sized,
-    {
-        // Convert LanguageIdentifier from unic_langid to icu_locid
-        let langid: icu_locid::LanguageIdentifier = lang.to_string().parse().map_err(|_| ())?;
-        args.make_formatter(&langid.into()).map_err(|_| ())
+    /// Adds a number of months, or subtracts them if negative
+    ///
+    /// The day of the month stays the same, unless the new month is
+    /// shorter: a month after January 31st is the last day of February.
+    /// Like [`add_days`](Self::add_days), this counts on the wall clock,
+    /// in the proleptic Gregorian calendar.  Returns `None` out of range.
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// let end_of_january = datetime!(2024-01-31 12:00);
+    /// let february = end_of_january.clone().add_months(1).unwrap();
+    /// assert_eq!((february.month(), february.day()), (2, 29));
+    /// let november = end_of_january.add_months(-2).unwrap();
+    /// assert_eq!((november.year(), november.month(), november.day()), (2023, 11, 30));
+    /// ```
+    pub fn add_months(self, months: i32) -> Option<Self> {
+        self.shift_local(|local| convert::add_months(local, months))
     }
-}
 
-/// Working around that intl_memoizer API, because IntlLangMemoizer doesn't
-/// expose the language it is caching
-///
-/// This would be a trivial addition but it isn't maintained these days.
-struct GimmeTheLocale(unic_langid::LanguageIdentifier);
+    /// Adds a number of years, or subtracts them if negative
+    ///
+    /// February 29th becomes February 28th in common years, see
+    /// [`add_months`](Self::add_months).
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// let leap_day = datetime!(2024-02-29 12:00).add_years(1).unwrap();
+    /// assert_eq!((leap_day.year(), leap_day.month(), leap_day.day()), (2025, 2, 28));
+    /// ```
+    pub fn add_years(self, years: i32) -> Option<Self> {
+        self.add_months(years.checked_mul(12)?)
+    }
+
+    /// Truncates to the start of the hour
+    ///
+    /// Like [`add_days`](Self::add_days), this works on the wall clock.
+    /// Returns `None` if the time zone has no data.
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// let hour = datetime!(1989-11-09 23:30).start_of_hour().unwrap();
+    /// assert_eq!((hour.hour(), hour.minute()), (23, 0));
+    /// ```
+    pub fn start_of_hour(self) -> Option<Self> {
+        self.shift_local(|local| {
+            let time = Time::try_new(local.time.hour.number(), 0, 0, 0).ok()?;
+            Some(icu_calendar::DateTime::new(local.date, time))
+        })
+    }
+
+    /// Truncates to the start of the day, for grouping by day
+    ///
+    /// With a time zone, this is midnight in that zone, or the first
+    /// time after it where daylight saving time skips midnight.
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// let datetime = datetime!(1989-11-09 23:30).with_utc_offset(3600).unwrap();
+    /// let day = datetime.start_of_day().unwrap();
+    /// // Fields are in UTC
+    /// assert_eq!((day.day(), day.hour()), (8, 23));
+    /// ```
+    pub fn start_of_day(self) -> Option<Self> {
+        self.shift_local(|local| Some(icu_calendar::DateTime::new(local.date, Time::midnight())))
+    }
+
+    /// Truncates to the start of the week, on the first day of the week
+    /// of the locale
+    ///
+    /// Weeks start on Monday in most of Europe, on Sunday in the US and
+    /// on Saturday in Egypt, see [`WeekInfo`], so week views built from
+    /// this have the boundaries users expect.  Locale data comes from the
+    /// bundle the options were set in, if it has its own data source,
+    /// like [`format`](Self::format).  Returns `None` if it is missing.
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// // A Thursday
+    /// let datetime = datetime!(1989-11-09 23:30);
+    /// let us = datetime.clone().start_of_week(&"en-US".parse()?).unwrap();
+    /// assert_eq!((us.month(), us.day(), us.hour()), (11, 5, 0));
+    /// let fr = datetime.clone().start_of_week(&"fr-FR".parse()?).unwrap();
+    /// assert_eq!((fr.month(), fr.day()), (11, 6));
+    /// let eg = datetime.start_of_week(&"ar-EG".parse()?).unwrap();
+    /// assert_eq!((eg.month(), eg.day()), (11, 4));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn start_of_week(self, locale: &unic_langid::LanguageIdentifier) -> Option<Self> {
+        let provider = self.options.provider()?;
+        let first_weekday = WeekInfo::try_new_with_provider(provider, locale)
+            .ok()?
+            .first_weekday();
+        self.shift_local(|local| {
+            let days = (local.date.day_of_week() as i64 - first_weekday as i64).rem_euclid(7);
+            let first_day = convert::shift_seconds(local, -days * 86_400)?;
+            Some(icu_calendar::DateTime::new(
+                first_day.date,
+                Time::midnight(),
+            ))
+        })
+    }
+
+    /// Truncates to the start of the month, in the proleptic Gregorian
+    /// calendar
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// let month = datetime!(1989-11-09 23:30).start_of_month().unwrap();
+    /// assert_eq!((month.month(), month.day(), month.hour()), (11, 1, 0));
+    /// ```
+    pub fn start_of_month(self) -> Option<Self> {
+        self.shift_local(|local| {
+            let date = local.date.to_iso();
+            let first =
+                Date::try_new_iso_date(date.year().number, date.month().ordinal as u8, 1).ok()?;
+            Some(icu_calendar::DateTime::new(
+                first.to_calendar(Gregorian),
+                Time::midnight(),
+            ))
+        })
+    }
+
+    /// Truncates to the start of the year, in the proleptic Gregorian
+    /// calendar
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// let year = datetime!(1989-11-09 23:30).start_of_year().unwrap();
+    /// assert_eq!((year.year(), year.month(), year.day()), (1989, 1, 1));
+    /// ```
+    pub fn start_of_year(self) -> Option<Self> {
+        self.shift_local(|local| {
+            let first = Date::try_new_iso_date(local.date.to_iso().year().number, 1, 1).ok()?;
+            Some(icu_calendar::DateTime::new(
+                first.to_calendar(Gregorian),
+                Time::midnight(),
+            ))
+        })
+    }
+
+    /// Changes the datetime as displayed, in its time zone if it has one
+    fn shift_local(
+        mut self,
+        shift: impl FnOnce(
+            &icu_calendar::DateTime<Gregorian>,
+        ) -> Option<icu_calendar::DateTime<Gregorian>>,
+    ) -> Option<Self> {
+        self.value = match &self.options.time_zone {
+            Some(tz) if self.semantics == Semantics::Instant => {
+                let local = shift(&tz.to_local(&self.value).ok()?)?;
+                tz.to_utc(&local, Disambiguation::Compatible).ok()?
+            }
+            _ => shift(&self.value)?,
+        };
+        Some(self)
+    }
+
+    /// The datetime in another calendar, as it is displayed
+    ///
+    /// With a time zone, this is the wall-clock time of that zone rather
+    /// than UTC.  Application logic, like finding the holidays around a
+    /// date, can then work on the value being formatted.
+    ///
+    /// ```
+    /// use fluent_datetime::{datetime, Disambiguation, TimeZone};
+    /// use icu_calendar::hebrew::Hebrew;
+    ///
+    /// let tz = TimeZone::from_iana("Etc/GMT-2").unwrap();
+    /// let datetime = datetime!(1989-11-10 01:30).in_time_zone(tz, Disambiguation::Reject)?;
+    /// assert_eq!(datetime.day(), 9);
+    /// let hebrew = datetime.to_calendar(Hebrew::new())?;
+    /// assert_eq!(hebrew.date.year().number, 5750);
+    /// assert_eq!(hebrew.date.month().code.0, "M02");
+    /// assert_eq!(hebrew.date.day_of_month().0, 12);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_calendar<A: AsCalendar>(
+        &self,
+        calendar: A,
+    ) -> Result<icu_calendar::DateTime<A>, FluentDateTimeError> {
+        let local = match &self.options.time_zone {
+            Some(tz) if self.semantics == Semantics::Instant => tz.to_local(&self.value)?,
+            _ => self.value,
+        };
+        Ok(local.to_calendar(calendar))
+    }
+
+    /// The ISO 8601 week date, like "1989-W45-4", for who plans in ISO
+    /// weeks
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one with
+    /// its first Thursday, so the week year can differ from the calendar
+    /// year around January 1st.  With a time zone, this is the date of
+    /// that zone, like [`to_calendar`](Self::to_calendar).  Fluent messages
+    /// get it with [`WeekNumber::IsoWeekDate`].
+    ///
+    /// ```
+    /// use fluent_datetime::datetime;
+    ///
+    /// assert_eq!(datetime!(1989-11-09 23:30).to_iso_week_date()?, "1989-W45-4");
+    /// assert_eq!(datetime!(2024-11-05 12:00).to_iso_week_date()?, "2024-W45-2");
+    /// assert_eq!(datetime!(2021-01-03 12:00).to_iso_week_date()?, "2020-W53-7");
+    /// assert_eq!(datetime!(2024-12-30 12:00).to_iso_week_date()?, "2025-W01-1");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_iso_week_date(&self) -> Result<String, FluentDateTimeError> {
+        Ok(week::iso_week_date(&self.to_calendar(Iso)?.date))
+    }
+
+    /// The year, numbered like ISO 8601 (year zero is 1 BCE)
+    pub fn year(&self) -> i32 {
+        self.value.date.to_iso().year().number
+    }
+
+    /// The month, from 1 to 12
+    pub fn month(&self) -> u32 {
+        self.value.date.month().ordinal
+    }
+
+    /// The day of the month, starting at 1
+    pub fn day(&self) -> u32 {
+        self.value.date.day_of_month().0
+    }
+
+    /// The hour, from 0 to 23
+    pub fn hour(&self) -> u8 {
+        self.value.time.hour.number()
+    }
+
+    /// The minute, from 0 to 59
+    pub fn minute(&self) -> u8 {
+        self.value.time.minute.number()
+    }
+
+    /// The second, from 0 to 60 (allowing for leap seconds)
+    pub fn second(&self) -> u8 {
+        self.value.time.second.number()
+    }
+
+    /// The fractional second, in nanoseconds
+    pub fn nanosecond(&self) -> u32 {
+        self.value.time.nanosecond.number()
+    }
+
+    /// Formats into a [`fmt::Write`] sink, for the memoizer's locale
+    ///
+    /// Formatters are cached in the memoizer, and the output is written
+    /// directly, so rendering loops can reuse the same buffer.  Nothing is
+    /// written if formatting fails.
+    ///
+    /// ```
+    /// use fluent_datetime::FluentDateTime;
+    /// use icu_calendar::DateTime;
+    /// use intl_memoizer::IntlLangMemoizer;
+    ///
+    /// let intls = IntlLangMemoizer::new("en-US".parse()?);
+    /// let mut buf = String::new();
+    /// for day in [9, 10] {
+    ///     let datetime = DateTime::try_new_iso_datetime(1989, 11, day, 23, 30, 0).unwrap();
+    ///     buf.clear();
+    ///     FluentDateTime::from(datetime).write_to(&mut buf, &intls)?;
+    ///     assert_eq!(buf, format!("11/{day}/89"));
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        intls: &intl_memoizer::IntlLangMemoizer,
+    ) -> fmt::Result {
+        self.try_write_to(sink, intls).unwrap_or(Err(fmt::Error))
+    }
+
+    fn try_write_to<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        intls: &intl_memoizer::IntlLangMemoizer,
+    ) -> Result<fmt::Result, LoadError> {
+        let options = self.options.for_value(self);
+        if let Some(cache) = &self.options.cache {
+            let lang = intls
+                .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+                .expect("Infallible");
+            let dtf = cache.get(&lang, &options)?;
+            return Ok(self.write_with(&dtf, &options, sink));
+        }
+        let args = (options.formatter_key(), provider::generation());
+        intls.with_try_get::<DateTimeFormatter, _, _>(args, |dtf| {
+            self.write_with(dtf, &options, sink)
+        })
+    }
+
+    /// Why [`write_with`](Self::write_with) failed
+    fn write_error(&self, options: &FluentDateTimeOptions) -> FluentDateTimeError {
+        match &options.time_zone {
+            Some(_) if self.semantics == Semantics::Floating => {
+                FluentDateTimeError::FloatingTimeZone
+            }
+            Some(tz) if tz.offset_at(&self.value).is_none() => {
+                FluentDateTimeError::UnknownTimeZone(tz.iana_id().to_string())
+            }
+            _ => FluentDateTimeError::Format,
+        }
+    }
+
+    /// Formats with a formatter built for `options`, refusing time zones
+    /// on floating datetimes
+    fn write_with<W: fmt::Write + ?Sized>(
+        &self,
+        dtf: &DateTimeFormatter,
+        options: &FluentDateTimeOptions,
+        sink: &mut W,
+    ) -> fmt::Result {
+        if self.semantics == Semantics::Floating && options.time_zone.is_some() {
+            return Err(fmt::Error);
+        }
+        dtf.write_to(&self.value, options, sink)
+    }
+
+    fn describe_failure(
+        &self,
+        lang: &unic_langid::LanguageIdentifier,
+        cause: Option<LoadError>,
+    ) -> String {
+        let mut description = format!(
+            "DATETIME: could not format {} for locale {lang}",
+            self.to_iso8601()
+        );
+        if let Some(cause) = cause {
+            let _ = write!(description, ": {cause}");
+        }
+        description
+    }
+
+    /// Reports a formatting failure and renders according to the
+    /// [`ErrorPolicy`]
+    fn render_failure(&self, describe: impl FnOnce() -> String) -> Cow<'static, str> {
+        match &self.options.error_policy {
+            Some(ErrorPolicy::DebugPanic) if cfg!(debug_assertions) => panic!("{}", describe()),
+            policy => {
+                report::report(describe);
+                match policy {
+                    Some(ErrorPolicy::Marker) => ErrorPolicy::MARKER.into(),
+                    Some(ErrorPolicy::Placeholder(placeholder)) => placeholder.to_string().into(),
+                    _ => self.to_iso8601().into(),
+                }
+            }
+        }
+    }
+
+    /// Formats with the attached options, for an explicit locale
+    ///
+    /// Handy for logging and tests that don't need a bundle.  Fails if no
+    /// formatter can be built for the locale and options, or if the time
+    /// zone can't be applied to the datetime.
+    ///
+    /// ```
+    /// use fluent_datetime::FluentDateTime;
+    /// use icu_calendar::DateTime;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let datetime = FluentDateTime::from(datetime);
+    /// assert_eq!(datetime.format(&"de-DE".parse()?).unwrap(), "09.11.89");
+    /// assert_eq!(datetime.display(&"en-US".parse()?).to_string(), "11/9/89");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<String, FluentDateTimeError> {
+        format_datetime(locale, &self.options.for_value(self), self)
+    }
+
+    /// A [`Display`](fmt::Display) adapter formatting for an explicit locale
+    ///
+    /// Falls back to ISO 8601 if formatting fails, like Fluent rendering does.
+    pub fn display<'a>(&'a self, locale: &'a unic_langid::LanguageIdentifier) -> Display<'a> {
+        Display {
+            value: self,
+            locale,
+        }
+    }
+
+    /// The fields of the datetime, in order of significance
+    fn instant(&self) -> (i32, u32, u32, u8, u8, u8, u32) {
+        (
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.nanosecond(),
+        )
+    }
+
+    /// A locale-independent key that sorts like the datetimes
+    ///
+    /// This is ISO 8601 with fixed-width fractional seconds, so tables can
+    /// show localized datetimes and sort them as strings.  Values displayed
+    /// in a time zone give their UTC time, so keys compare as instants.
+    /// Only years 0000 to 9999 sort correctly.
+    ///
+    /// ```
+    /// use fluent_datetime::FluentDateTime;
+    /// use icu_calendar::DateTime;
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let datetime = FluentDateTime::from(datetime);
+    /// assert_eq!(datetime.format(&"de-DE".parse()?).unwrap(), "09.11.89");
+    /// assert_eq!(datetime.sort_key(), "1989-11-09T23:30:00.000000000");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sort_key(&self) -> String {
+        let iso = self.value.to_iso();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}",
+            iso.date.year().number,
+            iso.date.month().ordinal,
+            iso.date.day_of_month().0,
+            iso.time.hour.number(),
+            iso.time.minute.number(),
+            iso.time.second.number(),
+            iso.time.nanosecond.number(),
+        )
+    }
+
+    /// Renders as ISO 8601, for when localized formatting fails
+    ///
+    /// Values displayed in a time zone are rendered in UTC.
+    fn to_iso8601(&self) -> String {
+        let iso = self.value.to_iso();
+        if let Some(fields) = self.options.fields {
+            return fields.to_iso8601(&iso.date);
+        }
+        let year = iso.date.year().number;
+        let mut s = if (0..=9999).contains(&year) {
+            format!("{year:04}")
+        } else {
+            format!("{year:+05}")
+        };
+        write!(
+            s,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            iso.date.month().ordinal,
+            iso.date.day_of_month().0,
+            iso.time.hour.number(),
+            iso.time.minute.number(),
+            iso.time.second.number(),
+        )
+        .expect("Writing to a String");
+        let nanos = iso.time.nanosecond.number();
+        if nanos != 0 {
+            let frac = format!("{nanos:09}");
+            s.push('.');
+            s.push_str(frac.trim_end_matches('0'));
+        }
+        if self.options.time_zone.is_some() {
+            s.push('Z');
+        }
+        s
+    }
+}
+
+impl PartialEq for FluentDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for FluentDateTime {}
+
+impl std::hash::Hash for FluentDateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
+}
+
+impl PartialOrd for FluentDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FluentDateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+impl FluentType for FluentDateTime {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        // Basically Clone
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        let mut s = String::new();
+        match self.try_write_to(&mut s, intls) {
+            Ok(Ok(())) => s.into(),
+            result => self.render_failure(|| {
+                let lang = intls
+                    .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+                    .expect("Infallible");
+                self.describe_failure(&lang, result.err())
+            }),
+        }
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        // Concurrent bundles can't memoize formatters, the traits don't
+        // work out, so those without a cache of their own share one
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let options = self.options.for_value(self);
+        let formatter = match &options.cache {
+            Some(cache) => cache.get(&lang, &options),
+            None => cache::shared().get(&lang, &options),
+        };
+        let mut s = String::new();
+        let formatted = formatter.map(|dtf| self.write_with(&dtf, &options, &mut s));
+        match formatted {
+            Ok(Ok(())) => s.into(),
+            result => self.render_failure(|| self.describe_failure(&lang, result.err())),
+        }
+    }
+}
+
+impl From<icu_calendar::DateTime<Gregorian>> for FluentDateTime {
+    fn from(value: icu_calendar::DateTime<Gregorian>) -> Self {
+        Self {
+            value,
+            semantics: Semantics::default(),
+            options: Default::default(),
+        }
+    }
+}
+
+impl From<icu_calendar::DateTime<Iso>> for FluentDateTime {
+    fn from(value: icu_calendar::DateTime<Iso>) -> Self {
+        Self {
+            value: value.to_calendar(Gregorian),
+            semantics: Semantics::default(),
+            options: Default::default(),
+        }
+    }
+}
+
+impl From<FluentDateTime> for FluentValue<'static> {
+    fn from(value: FluentDateTime) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+/// What building a formatter reads from [`FluentDateTimeOptions`]
+///
+/// Memoized and cached formatters are keyed on this rather than the
+/// options, so options that only change how values are written, like the
+/// time zone or the relative reference time, share a formatter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct FormatterKey {
+    date_style: Option<length::Date>,
+    time_style: Option<length::Time>,
+    week_number: Option<WeekNumber>,
+    era: Option<NameWidth>,
+    zoned: bool,
+    titlecase: bool,
+    relative: Option<relative::FormattersKey>,
+    numbering_system: Option<unicode::Value>,
+    calendar: Option<unicode::Value>,
+    hour_cycle: Option<HourCycle>,
+    // Two-digit hours, see Alignment
+    column: bool,
+    fields: Option<partial::Fields>,
+    provider: Option<SharedProvider>,
+    // Picks the locale when data is missing for the requested one
+    fallbacks: Arc<[unic_langid::LanguageIdentifier]>,
+}
+
+impl FormatterKey {
+    /// The bundle's data source if any, else the default one
+    fn provider(&self) -> Option<Provider<'_>> {
+        match &self.provider {
+            Some(provider) => Some(provider.get()),
+            None => provider::default_provider(),
+        }
+    }
+
+    /// Builds a formatter for `lang`, or else the first bundle locale
+    /// that has data
+    fn make_formatter_with_fallbacks(
+        &self,
+        provider: Provider,
+        lang: &unic_langid::LanguageIdentifier,
+    ) -> Result<DateTimeFormatter, LoadError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("build_formatter", locale = %lang).entered();
+        // Convert LanguageIdentifier from unic_langid to icu_locid
+        let candidates: Vec<icu_locid::LanguageIdentifier> = std::iter::once(lang)
+            .chain(self.fallbacks.iter().filter(|&fallback| fallback != lang))
+            .filter_map(|lang| lang.to_string().parse().ok())
+            .collect();
+        let langid = match candidates.as_slice() {
+            [] => return Err(LoadError::InvalidLocale),
+            [langid] => langid,
+            [first, ..] => candidates
+                .iter()
+                .find(|&langid| {
+                    provider::resolve_locale(provider, &langid.into())
+                        .is_some_and(|resolved| !resolved.is_und())
+                })
+                .unwrap_or(first),
+        };
+        #[cfg(feature = "tracing")]
+        if langid != &candidates[0] {
+            tracing::debug!(fallback = %langid, "no data for the locale, using a bundle locale");
+        }
+        let formatter = self.make_formatter(provider, langid);
+        #[cfg(feature = "tracing")]
+        match &formatter {
+            Ok(formatter) => tracing::debug!(resolved = %formatter.resolved, "formatter built"),
+            Err(e) => tracing::debug!(error = %e, "formatter not built"),
+        }
+        formatter
+    }
+
+    /// The locale to load data for, with the keywords of the options
+    fn data_locale(&self, langid: &icu_locid::LanguageIdentifier) -> icu_provider::DataLocale {
+        let mut locale = icu_locid::Locale::from(langid.clone());
+        let keywords = [
+            (unicode::key!("nu"), self.numbering_system.clone()),
+            (unicode::key!("ca"), self.calendar.clone()),
+            (
+                unicode::key!("hc"),
+                self.hour_cycle.map(HourCycle::to_keyword),
+            ),
+        ];
+        for (key, value) in keywords {
+            if let Some(value) = value {
+                locale.extensions.unicode.keywords.set(key, value);
+            }
+        }
+        icu_provider::DataLocale::from(&locale)
+    }
+
+    fn make_formatter(
+        &self,
+        provider: Provider,
+        langid: &icu_locid::LanguageIdentifier,
+    ) -> Result<DateTimeFormatter, LoadError> {
+        let locale = self.data_locale(langid);
+        let resolved: unic_langid::LanguageIdentifier = provider::resolve_locale(provider, &locale)
+            .and_then(|resolved| resolved.get_langid().to_string().parse().ok())
+            .unwrap_or_default();
+        #[cfg(feature = "tracing")]
+        if resolved.to_string() != langid.to_string() {
+            tracing::debug!(%resolved, "using locale data of a fallback locale");
+        }
+        let fallback = provider::Fallback::new(provider);
+        let provider = fallback.get();
+        let mut length = length::Bag::empty();
+        length.date = self.date_style;
+        // Full and long times show a zone; without one, they fall back to
+        // the medium time rather than failing
+        length.time = match self.time_style {
+            Some(length::Time::Full | length::Time::Long) if !self.zoned => {
+                Some(length::Time::Medium)
+            }
+            time_style => time_style,
+        };
+        if length == length::Bag::empty() {
+            length = length::Bag::from_date_style(length::Date::Short);
+        }
+        let options = match self.components(&length) {
+            Some(mut bag) => {
+                // Components ignore the -u-hc keyword and the locale's
+                // hour cycle
+                if bag.hour.is_some() {
+                    let hour_cycle = match self.hour_cycle {
+                        Some(hour_cycle) => hour_cycle,
+                        None => HourCycle::preferred(provider, &locale)?,
+                    };
+                    bag.preferences = Some(preferences::Bag::from_hour_cycle(
+                        hour_cycle.to_preference(),
+                    ));
+                }
+                bag.into()
+            }
+            None => length.into(),
+        };
+        // Weeks are counted on the local date, see write_localized
+        let kind = if self.zoned && self.week_number.is_none() {
+            FormatterKind::Zoned(
+                calendar::ZonedFormatter::try_new(provider, &locale, options)?,
+                provider::metazones(provider)?,
+            )
+        } else {
+            FormatterKind::Plain(calendar::PlainFormatter::try_new(
+                provider, &locale, options,
+            )?)
+        };
+        let titlecase = if self.titlecase {
+            Some(match provider {
+                #[cfg(feature = "compiled_data")]
+                Provider::Compiled => TitlecaseMapper::new(),
+                Provider::Any(p) => TitlecaseMapper::try_new_with_any_provider(&p)?,
+                #[cfg(feature = "buffer_provider")]
+                Provider::Buffer(p) => TitlecaseMapper::try_new_with_buffer_provider(&p)?,
+            })
+        } else {
+            None
+        };
+        Ok(DateTimeFormatter {
+            kind,
+            relative: self
+                .relative
+                .map(|relative| relative::RelativeFormatters::new(provider, &locale, relative)),
+            titlecase,
+            leap_months: lunisolar::LeapMonths::try_new(provider, &locale)?,
+            langid: langid.clone(),
+            resolved,
+        })
+    }
+
+    /// The components to format, for the options the styles can't
+    /// express: partial dates, week numbers, eras and two-digit hours
+    fn components(&self, length: &length::Bag) -> Option<components::Bag> {
+        if let Some(fields) = self.fields {
+            let date = length.date.unwrap_or(length::Date::Short);
+            return Some(fields.to_components(date, self.era));
+        }
+        if let Some(week_number) = self.week_number {
+            return Some(week_number.to_components());
+        }
+        if self.era.is_none() && !(self.column && length.time.is_some()) {
+            return None;
+        }
+        let mut bag = components::Bag::default();
+        if let Some(date) = length.date {
+            bag.era = self.era.map(NameWidth::to_text);
+            bag.year = Some(components::Year::Numeric);
+            bag.month = Some(match date {
+                length::Date::Full | length::Date::Long => components::Month::Long,
+                length::Date::Medium => components::Month::Short,
+                _ => components::Month::Numeric,
+            });
+            bag.day = Some(components::Day::NumericDayOfMonth);
+            if date == length::Date::Full {
+                bag.weekday = Some(components::Text::Long);
+            }
+        }
+        if let Some(time) = length.time {
+            bag.hour = Some(if self.column {
+                components::Numeric::TwoDigit
+            } else {
+                components::Numeric::Numeric
+            });
+            bag.minute = Some(components::Numeric::TwoDigit);
+            if time != length::Time::Short {
+                bag.second = Some(components::Numeric::TwoDigit);
+            }
+            bag.time_zone_name = match time {
+                length::Time::Full => Some(components::TimeZoneName::LongSpecific),
+                length::Time::Long => Some(components::TimeZoneName::ShortSpecific),
+                _ => None,
+            };
+        }
+        Some(bag)
+    }
+}
+
+struct DateTimeFormatter {
+    kind: FormatterKind,
+    relative: Option<relative::RelativeFormatters>,
+    titlecase: Option<TitlecaseMapper<CaseMapper>>,
+    // For the Chinese and Dangi calendars, see lunisolar
+    leap_months: Option<lunisolar::LeapMonths>,
+    langid: icu_locid::LanguageIdentifier,
+    // The locale whose data is used
+    resolved: unic_langid::LanguageIdentifier,
+}
+
+enum FormatterKind {
+    Plain(calendar::PlainFormatter),
+    Zoned(calendar::ZonedFormatter, MetazoneCalculator),
+}
+
+impl DateTimeFormatter {
+    /// Writes the formatted datetime, leaving the sink untouched on failure
+    fn write_to<W: fmt::Write + ?Sized>(
+        &self,
+        value: &icu_calendar::DateTime<Gregorian>,
+        options: &FluentDateTimeOptions,
+        sink: &mut W,
+    ) -> fmt::Result {
+        if options.alignment.unwrap_or_default().is_column() {
+            self.write_pseudo(value, options, &mut alignment::PlainSpaces(sink))
+        } else {
+            self.write_pseudo(value, options, sink)
+        }
+    }
+
+    fn write_pseudo<W: fmt::Write + ?Sized>(
+        &self,
+        value: &icu_calendar::DateTime<Gregorian>,
+        options: &FluentDateTimeOptions,
+        sink: &mut W,
+    ) -> fmt::Result {
+        match options.pseudo {
+            Some(pseudo) => {
+                let mut sink = pseudo::Writer::new(pseudo, sink);
+                self.write_controlled(value, options, &mut sink)?;
+                sink.finish()
+            }
+            None => self.write_controlled(value, options, sink),
+        }
+    }
+
+    fn write_controlled<W: fmt::Write + ?Sized>(
+        &self,
+        value: &icu_calendar::DateTime<Gregorian>,
+        options: &FluentDateTimeOptions,
+        sink: &mut W,
+    ) -> fmt::Result {
+        if options.bidi_controls.unwrap_or_default().is_keep() {
+            self.write_cased(value, options, sink)
+        } else {
+            self.write_cased(value, options, &mut StripBidiControls(sink))
+        }
+    }
+
+    fn write_cased<W: fmt::Write + ?Sized>(
+        &self,
+        value: &icu_calendar::DateTime<Gregorian>,
+        options: &FluentDateTimeOptions,
+        sink: &mut W,
+    ) -> fmt::Result {
+        match &self.titlecase {
+            // Titlecasing needs the whole string
+            Some(titlecase) => {
+                let mut s = String::new();
+                self.write_localized(value, options, &mut s)?;
+                sink.write_str(&casing::titlecase(titlecase, &s, &self.langid))
+            }
+            None => self.write_localized(value, options, sink),
+        }
+    }
+
+    fn write_localized<W: fmt::Write + ?Sized>(
+        &self,
+        value: &icu_calendar::DateTime<Gregorian>,
+        options: &FluentDateTimeOptions,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let time_zone = options.time_zone.as_ref();
+        let local = || match time_zone {
+            Some(tz) => tz.to_local(value).map_err(|_| fmt::Error),
+            None => Ok(*value),
+        };
+        // Partial dates have no week, and aren't relative to now
+        let partial = options.fields.is_some();
+        if !partial && options.week_number == Some(WeekNumber::IsoWeekDate) {
+            return sink.write_str(&week::iso_week_date(&local()?.date.to_iso()));
+        }
+        if let (false, Some(formatters), Some(relative)) =
+            (partial, &self.relative, &options.relative)
+        {
+            let offset = match time_zone {
+                Some(tz) => tz.offset_at(value).ok_or(fmt::Error)?,
+                None => 0,
+            };
+            let unix = convert::to_unix(value);
+            if formatters.write_to(relative, unix, offset.into(), sink)? {
+                return Ok(());
+            }
+        }
+        match &self.kind {
+            FormatterKind::Plain(dtf) => {
+                dtf.write_to(local()?.to_iso(), self.leap_months.as_ref(), sink)
+            }
+            FormatterKind::Zoned(dtf, metazones) => {
+                let tz = time_zone.ok_or(fmt::Error)?;
+                let (offset, dst) = tz.offset_info_at(value).ok_or(fmt::Error)?;
+                let local = convert::shift_seconds(value, offset.into()).ok_or(fmt::Error)?;
+                let custom_tz = tz
+                    .to_custom_time_zone(metazones, &local, offset, dst)
+                    .ok_or(fmt::Error)?;
+                dtf.write_to(local.to_iso(), &custom_tz, self.leap_months.as_ref(), sink)
+            }
+        }
+    }
+}
+
+/// Drops bidi controls on their way to the sink
+struct StripBidiControls<'a, W: ?Sized>(&'a mut W);
+
+impl<W: fmt::Write + ?Sized> fmt::Write for StripBidiControls<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for part in s.split(|ch| {
+            matches!(
+                ch,
+                '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+            )
+        }) {
+            self.0.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+impl intl_memoizer::Memoizable for DateTimeFormatter {
+    /// What building reads from the options, and the data
+    /// [`generation`](provider::generation)
+    type Args = (FormatterKey, u64);
+
+    type Error = LoadError;
+
+    fn construct(
+        lang: unic_langid::LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: std::marker::Sized,
+    {
+        let (key, _) = args;
+        key.make_formatter_with_fallbacks(key.provider().ok_or(LoadError::NoProvider)?, &lang)
+    }
+}
+
+/// Formats datetimes for a fixed locale and options, outside of Fluent
+///
+/// This lets applications keep datetimes consistent between Fluent
+/// messages and other UI strings.  The options the formatted values carry
+/// are ignored in favor of the formatter's.
+///
+/// ```
+/// use fluent_datetime::{FluentDateTime, FluentDateTimeFormatter, FluentDateTimeOptions};
+/// use icu_calendar::DateTime;
+/// use icu_datetime::options::length;
+///
+/// let mut options = FluentDateTimeOptions::default();
+/// options.set_date_style(Some(length::Date::Long));
+/// let formatter = FluentDateTimeFormatter::try_new(&"fr-FR".parse()?, options).unwrap();
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let datetime = FluentDateTime::from(datetime);
+/// assert_eq!(formatter.format(&datetime).unwrap(), "9 novembre 1989");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct FluentDateTimeFormatter {
+    options: FluentDateTimeOptions,
+    formatter: DateTimeFormatter,
+}
+
+impl FluentDateTimeFormatter {
+    /// Creates a formatter
+    ///
+    /// Fails if locale data is missing or the options can't be satisfied,
+    /// see [`LoadError`].  Locale data comes from the bundle the options were set
+    /// in, if it has its own data source, else from the registered data
+    /// blobs, if any, else from compiled data; without the `compiled_data`
+    /// feature, use [`try_new_with_any_provider`](Self::try_new_with_any_provider)
+    /// instead.
+    pub fn try_new(
+        locale: &unic_langid::LanguageIdentifier,
+        options: FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        let langid: icu_locid::LanguageIdentifier = locale
+            .to_string()
+            .parse()
+            .map_err(|_| LoadError::InvalidLocale)?;
+        let key = options.formatter_key();
+        let formatter =
+            key.make_formatter(key.provider().ok_or(LoadError::NoProvider)?, &langid)?;
+        Ok(Self { options, formatter })
+    }
+
+    /// Creates a formatter that loads locale data from an [`AnyProvider`]
+    ///
+    /// This lets applications ship their own, possibly trimmed or updated,
+    /// CLDR data.
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTimeFormatter, FluentDateTimeOptions};
+    /// use icu_provider_adapters::empty::EmptyDataProvider;
+    ///
+    /// let provider = EmptyDataProvider::new();
+    /// let options = FluentDateTimeOptions::default();
+    /// assert!(FluentDateTimeFormatter::try_new_with_any_provider(
+    ///     &provider, &"en-US".parse()?, options
+    /// ).unwrap_err().is_missing_data());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`AnyProvider`]: icu_provider::AnyProvider
+    pub fn try_new_with_any_provider(
+        provider: &(impl icu_provider::AnyProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+        options: FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Any(&provider), locale, options)
+    }
+
+    /// Creates a formatter that loads locale data from a [`BufferProvider`],
+    /// such as a data blob loaded at runtime
+    ///
+    /// [`BufferProvider`]: icu_provider::BufferProvider
+    #[cfg(feature = "buffer_provider")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl icu_provider::BufferProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+        options: FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Buffer(&provider), locale, options)
+    }
+
+    fn try_new_with_provider(
+        provider: Provider,
+        locale: &unic_langid::LanguageIdentifier,
+        options: FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        let langid: icu_locid::LanguageIdentifier = locale
+            .to_string()
+            .parse()
+            .map_err(|_| LoadError::InvalidLocale)?;
+        let formatter = options.formatter_key().make_formatter(provider, &langid)?;
+        Ok(Self { options, formatter })
+    }
+
+    /// The options this formatter applies
+    pub fn options(&self) -> &FluentDateTimeOptions {
+        &self.options
+    }
+
+    /// The locale whose data is used
+    ///
+    /// Locales without datetime data fall back to their parent, for
+    /// example `de-CH` to `de`, or to `und` (root data) as a last resort.
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTimeFormatter, FluentDateTimeOptions};
+    ///
+    /// let options = FluentDateTimeOptions::default();
+    /// let formatter = FluentDateTimeFormatter::try_new(&"de-DE".parse()?, options.clone()).unwrap();
+    /// assert_eq!(formatter.resolved_locale().to_string(), "de");
+    /// let formatter = FluentDateTimeFormatter::try_new(&"gsw".parse()?, options).unwrap();
+    /// assert_eq!(formatter.resolved_locale().to_string(), "und");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolved_locale(&self) -> &unic_langid::LanguageIdentifier {
+        &self.formatter.resolved
+    }
+
+    /// Formats a datetime into a [`fmt::Write`] sink
+    ///
+    /// Nothing is written if formatting fails.
+    pub fn write_to<W: fmt::Write + ?Sized>(
+        &self,
+        value: &FluentDateTime,
+        sink: &mut W,
+    ) -> fmt::Result {
+        value.write_with(&self.formatter, &self.options, sink)
+    }
+
+    /// Formats a datetime to a string
+    ///
+    /// Returns `None` if formatting fails.
+    pub fn format(&self, value: &FluentDateTime) -> Option<String> {
+        let mut s = String::new();
+        self.write_to(value, &mut s).ok()?;
+        Some(s)
+    }
+
+    /// Formats many datetimes, passing each result to `f` in turn
+    ///
+    /// The output buffer is reused across values, which suits writing
+    /// tables or CSV exports.  `f` gets `None` for values that fail to
+    /// format.
+    ///
+    /// ```
+    /// use fluent_datetime::{FluentDateTime, FluentDateTimeFormatter, FluentDateTimeOptions};
+    /// use icu_calendar::DateTime;
+    ///
+    /// let formatter =
+    ///     FluentDateTimeFormatter::try_new(&"en-US".parse()?, FluentDateTimeOptions::default())
+    ///         .unwrap();
+    /// let days: Vec<FluentDateTime> = (1..=3)
+    ///     .map(|day| DateTime::try_new_iso_datetime(2024, 2, day, 0, 0, 0).unwrap().into())
+    ///     .collect();
+    /// let mut csv = String::new();
+    /// formatter.format_each(&days, |s| {
+    ///     csv.push_str(s.unwrap_or(""));
+    ///     csv.push('\n');
+    /// });
+    /// assert_eq!(csv, "2/1/24\n2/2/24\n2/3/24\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_each<'v>(
+        &self,
+        values: impl IntoIterator<Item = &'v FluentDateTime>,
+        mut f: impl FnMut(Option<&str>),
+    ) {
+        let mut buf = String::new();
+        for value in values {
+            buf.clear();
+            match self.write_to(value, &mut buf) {
+                Ok(()) => f(Some(&buf)),
+                Err(fmt::Error) => f(None),
+            }
+        }
+    }
+
+    /// Formats many datetimes to strings
+    ///
+    /// Same as calling [`format`](Self::format) on each value.
+    pub fn format_all<'v>(
+        &self,
+        values: impl IntoIterator<Item = &'v FluentDateTime>,
+    ) -> Vec<Option<String>> {
+        let mut out = vec![];
+        self.format_each(values, |s| out.push(s.map(str::to_owned)));
+        out
+    }
+}
+
+impl std::fmt::Debug for FluentDateTimeFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FluentDateTimeFormatter")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Displays a [`FluentDateTime`] for a locale
+///
+/// Returned by [`FluentDateTime::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct Display<'a> {
+    value: &'a FluentDateTime,
+    locale: &'a unic_langid::LanguageIdentifier,
+}
+
+impl fmt::Display for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.format(self.locale) {
+            Ok(s) => f.write_str(&s),
+            Err(_) => f.write_str(&self.value.to_iso8601()),
+        }
+    }
+}
+
+/// Formats a datetime for a locale, outside of Fluent
+///
+/// `options` replace the options carried by `value`.  Fails like
+/// [`FluentDateTime::format`].  When formatting many values, build a
+/// [`FluentDateTimeFormatter`] once instead.
+pub fn format_datetime(
+    locale: &unic_langid::LanguageIdentifier,
+    options: &FluentDateTimeOptions,
+    value: &FluentDateTime,
+) -> Result<String, FluentDateTimeError> {
+    let formatter = FluentDateTimeFormatter::try_new(locale, options.clone())?;
+    let mut s = String::new();
+    formatter
+        .write_to(value, &mut s)
+        .map_err(|_| value.write_error(&formatter.options))?;
+    Ok(s)
+}
+
+/// Working around that intl_memoizer API, because IntlLangMemoizer doesn't
+/// expose the language it is caching
+///
+/// This would be a trivial addition but it isn't maintained these days.
+struct GimmeTheLocale(unic_langid::LanguageIdentifier);
 
 impl intl_memoizer::Memoizable for GimmeTheLocale {
     type Args = ();
@@ -375,6 +2467,7 @@ impl intl_memoizer::Memoizable for GimmeTheLocale {
 /// today-is-fulldate = Today is {DATETIME($date, dateStyle: "full")}
 /// now-is-time = Now is {DATETIME($date, timeStyle: "medium")}
 /// now-is-datetime = Now is {DATETIME($date, dateStyle: "full", timeStyle: "short")}
+/// now-is-local = Now is {DATETIME($date, $timezone, timeStyle: "short")}
 /// ````
 ///
 /// See [`DATETIME` in the Fluent guide][datetime-fluent]
@@ -384,31 +2477,849 @@ impl intl_memoizer::Memoizable for GimmeTheLocale {
 /// We currently implement only a subset of the formatting options:
 /// * `dateStyle`
 /// * `timeStyle`
+/// * `timeZone`
+/// * `calendar`, see [`FluentDateTimeOptions::set_calendar`]
+/// * `era`, see [`FluentDateTimeOptions::set_era`]
+/// * `hourCycle`, see [`FluentDateTimeOptions::set_hour_cycle`]
+/// * `numberingSystem`, see [`FluentDateTimeOptions::set_numbering_system`]
+///
+/// And these extensions:
+/// * `casing`: `middle-of-sentence` (the default), `beginning-of-sentence`
+///   or `standalone`, see [`Casing`]
+/// * `relative: "auto"`, with an optional `relativeThreshold` in seconds,
+///   to write "yesterday" or "in 2 hours" close to the time of formatting,
+///   see [`Relative`]; `relativeUnit` counts in one unit, from `second` to
+///   `year`, and `relativeNumeric: "always"` writes "1 day ago" rather than
+///   "yesterday", like the options of `Intl.RelativeTimeFormat`;
+///   `relativeRounding` is `floor` (the default), `round` or `ceil`, see
+///   [`RelativeRounding`], and `relativeNowCutoff` writes "now" that many
+///   seconds around the time of formatting
+/// * `utcOffset`: a UTC offset like `"+02:00"`, or a number of minutes
+///   east of UTC, for displaying the datetime at that offset rather than
+///   in a time zone, see [`TimeZone::from_offset_seconds`]
+/// * `alignment: "column"`, for two-digit hours and plain spaces that line
+///   up in tables, see [`Alignment`]
+/// * `weekNumber`: `numeric` or `withYear`, to write the week of the year
+///   instead of the date, or `isoWeekDate`, for ISO 8601 week dates like
+///   "2024-W45-2", see [`WeekNumber`]
+///
+/// Besides [`FluentDateTime`] values, the first argument can be a
+/// [`FluentYearMonth`], which formats without its day, a
+/// [`FluentMonthDay`], without its year, or a [`FluentDateInterval`],
+/// which formats both of its ends.
 ///
-/// Unknown options and extra positional arguments are ignored, unknown values
-/// of known options cause the date to be returned as-is.
+/// An optional second positional argument gives an IANA time zone,
+/// typically a user preference passed at runtime; a `timeZone` or
+/// `utcOffset` option takes precedence over it.  See [`TimeZone`].  Since
+/// Fluent options are literals, per-user UTC offsets are passed there too,
+/// like `utcOffset` values:
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{datetime, BundleExt};
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support()?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "now = {DATETIME($date, $offset, timeStyle: \"long\")}
+/// in-india = {DATETIME($date, timeStyle: \"long\", utcOffset: \"+05:30\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let now = bundle.get_message("now").unwrap().value().unwrap();
+/// let in_india = bundle.get_message("in-india").unwrap().value().unwrap();
+///
+/// let mut errors = vec![];
+/// let mut args = FluentArgs::new();
+/// args.set("date", datetime!(1989-11-09 23:30));
+/// args.set("offset", -300);
+/// assert_eq!(bundle.format_pattern(now, Some(&args), &mut errors), "6:30:00\u{202f}PM GMT-05:00");
+/// assert_eq!(bundle.format_pattern(in_india, Some(&args), &mut errors), "5:00:00\u{202f}AM GMT+05:30");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Unknown options and extra positional arguments are ignored.  Like in
+/// fluent-js, invalid values of known options are reported, see
+/// [`BundleExt::format_pattern_reporting`], and the datetime is formatted
+/// without them.
+///
+/// When the datetime can't be formatted for the bundle locale, for example
+/// because locale data is missing or the options can't be satisfied, it is
+/// rendered as ISO 8601 (`1989-11-09T23:30:00`) rather than disappearing
+/// from the message.  [`DateTimeSupport::with_error_policy`] changes this.
 ///
 /// [datetime-fluent]: https://projectfluent.org/fluent/guide/functions.html#datetime
 /// [Intl.DateTimeFormat]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat
 /// [ECMA 402]: https://tc39.es/ecma402/#sec-createdatetimeformat
 #[allow(non_snake_case)]
 pub fn DATETIME<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
-    match positional.get(0) {
+    datetime_with_support(positional, named, &DateTimeSupport::default())
+}
+
+/// The signature of the Fluent functions in [`FUNCTIONS`]
+pub type FluentFunction = for<'a> fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a>;
+
+/// Every Fluent function of this crate, by name
+///
+/// For frameworks that register plain functions rather than going through
+/// [`BundleExt`], so they pick up new functions without listing them.
+/// With [l10n](https://lib.rs/crates/l10n), whose `init!` macro needs the
+/// names spelled out, see [`DATETIME`].
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentBundle, FluentResource};
+///
+/// let mut bundle = FluentBundle::<FluentResource>::new(vec!["en-US".parse()?]);
+/// for &(name, function) in fluent_datetime::FUNCTIONS {
+///     bundle.add_function(name, function)?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub const FUNCTIONS: &[(&str, FluentFunction)] = &[
+    ("DATETIME", DATETIME),
+    ("WEEKDAY", WEEKDAY),
+    ("DURATION", DURATION),
+];
+
+fn datetime_with_support<'a>(
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+    support: &DateTimeSupport,
+) -> FluentValue<'a> {
+    if let Some(warnings) = &support.warnings {
+        warnings.check(positional, named);
+    }
+    if let Some(FluentValue::Custom(cus)) = positional.first() {
+        if let Some(interval) = cus.as_any().downcast_ref::<FluentDateInterval>() {
+            return interval::with_support(interval, positional, named, support);
+        }
+    }
+    match resolve_datetime(positional, named, support) {
+        Ok(dt) => FluentValue::Custom(Box::new(dt)),
+        Err((dt, description)) => {
+            if let (None, Some(custom @ FluentValue::Custom(_))) = (&dt, positional.first()) {
+                if support.custom_passthrough {
+                    report::report(|| format!("{description}, rendered as is"));
+                    return custom.clone();
+                }
+            }
+            if let (None, Some(fallback)) = (&dt, &support.argument_fallback) {
+                report::report(|| description);
+                return fallback.render(positional.first());
+            }
+            let policy = match &dt {
+                Some(dt) => dt.options.error_policy.clone(),
+                None => support.error_policy.clone(),
+            };
+            if policy == Some(ErrorPolicy::DebugPanic) && cfg!(debug_assertions) {
+                panic!("{description}");
+            }
+            report::report(|| description);
+            match (policy, dt) {
+                (Some(ErrorPolicy::Iso8601), Some(dt)) => {
+                    FluentValue::String(dt.to_iso8601().into())
+                }
+                (Some(ErrorPolicy::Placeholder(placeholder)), _) => {
+                    FluentValue::String(placeholder.to_string().into())
+                }
+                // Like fluent-js, keep the datetime, without the invalid
+                // options
+                (None | Some(ErrorPolicy::DebugPanic), Some(dt)) => FluentValue::Custom(dt),
+                _ => FluentValue::Error,
+            }
+        }
+    }
+}
+
+/// The datetime a DATETIME call evaluates to
+///
+/// On failure, gives the datetime if there is one, and a description.
+fn resolve_datetime(
+    positional: &[FluentValue],
+    named: &FluentArgs,
+    support: &DateTimeSupport,
+) -> Result<FluentDateTime, (Option<Box<FluentDateTime>>, String)> {
+    match positional.first() {
         Some(FluentValue::Custom(cus)) => {
-            if let Some(dt) = cus.as_any().downcast_ref::<FluentDateTime>() {
-                let mut dt = dt.clone();
-                let Ok(()) = dt.options.merge_args(named) else {
-                    return FluentValue::Error;
-                };
-                FluentValue::Custom(Box::new(dt))
-            } else {
-                FluentValue::Error
+            let dt = match as_datetime(&positional[0]).or_else(|| partial::as_datetime(&**cus)) {
+                Some(dt) => Some(dt.clone()),
+                None => support.extractors.extract(&**cus),
+            };
+            let Some(mut dt) = dt else {
+                return Err((None, format!("DATETIME: unsupported argument {cus:?}")));
+            };
+            support.apply(&mut dt.options);
+            let mut error = None;
+            match positional.get(1) {
+                Some(FluentValue::String(tz)) => match TimeZone::from_argument(tz) {
+                    Some(tz) => dt.options.time_zone = Some(tz),
+                    None => error = Some(FluentDateTimeError::UnknownTimeZone(tz.to_string())),
+                },
+                Some(FluentValue::Number(minutes)) => {
+                    match TimeZone::from_offset_minutes(minutes.value) {
+                        Some(tz) => dt.options.time_zone = Some(tz),
+                        None => {
+                            error = Some(FluentDateTimeError::InvalidOption {
+                                name: "utcOffset".to_string(),
+                                value: minutes.value.to_string(),
+                            })
+                        }
+                    }
+                }
+                Some(FluentValue::None) | None => (),
+                Some(_) => {
+                    error = Some(FluentDateTimeError::WrongOptionType {
+                        name: "timeZone".to_string(),
+                        expected: "a string",
+                    });
+                }
+            }
+            if let Err(e) = dt.options.merge_args(named) {
+                error.get_or_insert(e);
+            }
+            if dt.semantics == Semantics::Floating && dt.options.time_zone.is_some() {
+                dt.options.time_zone = None;
+                error.get_or_insert(FluentDateTimeError::FloatingTimeZone);
+            }
+            if let Some(defaults) = &support.defaults {
+                dt.options.fill_from(defaults);
+                if dt.semantics == Semantics::Floating {
+                    dt.options.time_zone = None;
+                }
+            }
+            match error {
+                Some(e) => Err((Some(Box::new(dt)), format!("DATETIME: {e}"))),
+                None => Ok(dt),
             }
         }
         // https://github.com/projectfluent/fluent/wiki/Error-Handling
         // argues for graceful recovery (think lingering trauma from XUL DTD
         // errors)
-        _ => FluentValue::Error,
+        _ => Err((
+            None,
+            format!("DATETIME: {}", FluentDateTimeError::MissingDateTime),
+        )),
+    }
+}
+
+type Extractor = dyn Fn(&dyn FluentType) -> Option<FluentDateTime> + Send + Sync;
+
+/// Lets [`DATETIME`] accept custom Fluent values besides [`FluentDateTime`]
+///
+/// Frameworks sometimes wrap dates in their own [`FluentType`]; register
+/// a conversion for each such type, then pass the extractors to
+/// [`BundleExt::add_datetime_support_with_extractors`].
+///
+/// ```
+/// use std::borrow::Cow;
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+/// use fluent_datetime::fluent_bundle::types::FluentType;
+/// use fluent_datetime::{BundleExt, DateTimeExtractors, FluentDateTime};
+/// use icu_calendar::DateTime;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct AppDate(DateTime<icu_calendar::Iso>);
+///
+/// impl FluentType for AppDate {
+///     fn duplicate(&self) -> Box<dyn FluentType + Send> {
+///         Box::new(self.clone())
+///     }
+///     fn as_string(&self, _: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+///         "some date".into()
+///     }
+///     fn as_string_threadsafe(
+///         &self,
+///         _: &intl_memoizer::concurrent::IntlLangMemoizer,
+///     ) -> Cow<'static, str> {
+///         "some date".into()
+///     }
+/// }
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support_with_extractors(
+///     DateTimeExtractors::new()
+///         .with(|date: &AppDate| Some(FluentDateTime::from(date.0.clone()))),
+/// )?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "due = Due {DATETIME($date, dateStyle: \"long\")}".to_string(),
+/// ).unwrap()).unwrap();
+///
+/// let date = AppDate(DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap());
+/// let mut errors = vec![];
+/// assert_eq!(
+///     bundle.format_pattern(
+///         bundle.get_message("due").unwrap().value().unwrap(),
+///         Some(&FluentArgs::from_iter([("date", FluentValue::Custom(Box::new(date)))])),
+///         &mut errors),
+///     "Due \u{2068}November 9, 1989\u{2069}"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct DateTimeExtractors {
+    extractors: Vec<Arc<Extractor>>,
+}
+
+impl DateTimeExtractors {
+    /// Creates an empty set of extractors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a conversion from a custom Fluent type
+    ///
+    /// The conversion may return `None` for values it can't represent;
+    /// [`DATETIME`] will then return an error value.
+    pub fn with<T: FluentType>(
+        mut self,
+        extract: impl Fn(&T) -> Option<FluentDateTime> + Send + Sync + 'static,
+    ) -> Self {
+        self.extractors
+            .push(Arc::new(move |value: &dyn FluentType| {
+                value.as_any().downcast_ref::<T>().and_then(&extract)
+            }));
+        self
+    }
+
+    fn extract(&self, value: &dyn FluentType) -> Option<FluentDateTime> {
+        self.extractors.iter().find_map(|extract| extract(value))
+    }
+}
+
+impl std::fmt::Debug for DateTimeExtractors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DateTimeExtractors")
+            .field("len", &self.extractors.len())
+            .finish()
+    }
+}
+
+/// Whether to keep the bidi control characters of formatted datetimes
+///
+/// Locale data for right-to-left languages inserts directional marks
+/// (such as U+200F RIGHT-TO-LEFT MARK) so that datetimes display correctly
+/// in bidi-aware renderers.  Plain-text sinks like emails or logs may show
+/// them as garbage, or break when parsing.
+///
+/// This only concerns the datetime's own output, not the isolation
+/// characters Fluent puts around placeables, which are controlled by
+/// [`FluentBundle::set_use_isolating`].
+///
+/// ```
+/// use fluent_datetime::{BidiControls, FluentDateTime};
+/// use icu_calendar::DateTime;
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut datetime = FluentDateTime::from(datetime);
+/// assert_eq!(datetime.format(&"ar".parse()?).unwrap(), "9\u{200f}/11\u{200f}/1989");
+///
+/// datetime.options.set_bidi_controls(Some(BidiControls::Strip));
+/// assert_eq!(datetime.format(&"ar".parse()?).unwrap(), "9/11/1989");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BidiControls {
+    /// Keep them, as provided by locale data
+    #[default]
+    Keep,
+    /// Remove directional marks, embeddings, overrides and isolates
+    Strip,
+}
+
+impl BidiControls {
+    fn is_keep(self) -> bool {
+        self == Self::Keep
+    }
+}
+
+/// Configures the [`DATETIME`] function of a bundle
+///
+/// Pass this to [`BundleExt::add_datetime_support_with`].
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, DateTimeSupport, FluentDateTime, Pseudolocalization};
+/// use icu_calendar::DateTime;
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support_with(
+///     DateTimeSupport::new().with_pseudo(Pseudolocalization::Accented),
+/// )?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "today = {DATETIME($date, dateStyle: \"medium\")}".to_string(),
+/// ).unwrap()).unwrap();
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut errors = vec![];
+/// assert_eq!(
+///     bundle.format_pattern(
+///         bundle.get_message("today").unwrap().value().unwrap(),
+///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime))])),
+///         &mut errors),
+///     "[Ƞǿṽ 9, 1989]"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DateTimeSupport {
+    extractors: DateTimeExtractors,
+    bidi_controls: Option<BidiControls>,
+    pseudo: Option<Pseudolocalization>,
+    numbering_system: Option<unicode::Value>,
+    calendar: Option<unicode::Value>,
+    hour_cycle: Option<HourCycle>,
+    error_policy: Option<ErrorPolicy>,
+    argument_fallback: Option<ArgumentFallback>,
+    custom_passthrough: bool,
+    warnings: Option<warning::Warnings>,
+    defaults: Option<FluentDateTimeOptions>,
+    provider: Option<SharedProvider>,
+    // Filled in when registering on a bundle
+    locales: Arc<[unic_langid::LanguageIdentifier]>,
+    cache: Option<FormatterCache>,
+}
+
+impl DateTimeSupport {
+    /// The default configuration, same as [`BundleExt::add_datetime_support`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the bundle configuration, before the options of the message
+    fn apply(&self, options: &mut FluentDateTimeOptions) {
+        if self.bidi_controls.is_some() {
+            options.bidi_controls = self.bidi_controls;
+        }
+        if self.pseudo.is_some() {
+            options.pseudo = self.pseudo;
+        }
+        if options.numbering_system.is_none() {
+            options.numbering_system = self.numbering_system.clone();
+        }
+        if options.calendar.is_none() {
+            options.calendar = self.calendar.clone();
+        }
+        if options.hour_cycle.is_none() {
+            options.hour_cycle = self.hour_cycle;
+        }
+        if options.error_policy.is_none() {
+            options.error_policy = self.error_policy.clone();
+        }
+        if self.provider.is_some() {
+            options.provider = self.provider.clone();
+        }
+        options.fallbacks = self.locales.clone();
+        if self.cache.is_some() {
+            options.cache = self.cache.clone();
+        }
+    }
+
+    /// Builds the formatters of a bundle ahead of time, in the cache set
+    /// with [`with_cache`](Self::with_cache)
+    ///
+    /// This covers the [`DATETIME`] calls of `resources`, for a bundle
+    /// with this configuration and these locales, so the first request
+    /// doesn't wait for locale data to load.  Options given by variables
+    /// can't be anticipated.  Does nothing without a cache.
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::FluentResource;
+    /// use fluent_datetime::{DateTimeSupport, FormatterCache};
+    ///
+    /// let resource = FluentResource::try_new(
+    ///     "today = {DATETIME($date, dateStyle: \"long\")}".to_string(),
+    /// ).unwrap();
+    /// let cache = FormatterCache::new();
+    /// let support = DateTimeSupport::new().with_cache(cache.clone());
+    /// support.warm_up(&["en-US".parse()?], [&resource])?;
+    /// support.warm_up(&["fr-FR".parse()?], [&resource])?;
+    /// assert_eq!(cache.len(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn warm_up<'a>(
+        &self,
+        bundle_locales: &[unic_langid::LanguageIdentifier],
+        resources: impl IntoIterator<Item = &'a FluentResource>,
+    ) -> Result<(), LoadError> {
+        let (Some(cache), Some(lang)) = (&self.cache, bundle_locales.first()) else {
+            return Ok(());
+        };
+        let mut base = FluentDateTimeOptions::default();
+        let support = Self {
+            locales: bundle_locales.into(),
+            ..self.clone()
+        };
+        support.apply(&mut base);
+        for call in scan::datetime_calls(resources) {
+            if let Ok(mut options) = call.apply(base.clone()) {
+                if let Some(defaults) = &self.defaults {
+                    options.fill_from(defaults);
+                }
+                cache.get(lang, &options)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Accept additional input types
+    ///
+    /// See [`DateTimeExtractors`].
+    pub fn with_extractors(mut self, extractors: DateTimeExtractors) -> Self {
+        self.extractors = extractors;
+        self
+    }
+
+    /// Keep or strip bidi controls in every datetime formatted through [`DATETIME`]
+    ///
+    /// See [`BidiControls`].
+    pub fn with_bidi_controls(mut self, bidi_controls: BidiControls) -> Self {
+        self.bidi_controls = Some(bidi_controls);
+        self
+    }
+
+    /// Pseudo-localize every datetime formatted through [`DATETIME`]
+    ///
+    /// See [`Pseudolocalization`].
+    pub fn with_pseudo(mut self, pseudo: Pseudolocalization) -> Self {
+        self.pseudo = Some(pseudo);
+        self
+    }
+
+    /// Default numbering system for the bundle, standing in for the `-u-nu`
+    /// extension Fluent locales can't carry
+    ///
+    /// A `numberingSystem` option on the message takes precedence.  See
+    /// [`FluentDateTimeOptions::set_numbering_system`].
+    pub fn with_numbering_system(mut self, numbering_system: unicode::Value) -> Self {
+        self.numbering_system = Some(numbering_system);
+        self
+    }
+
+    /// Default output calendar for the bundle, standing in for the `-u-ca`
+    /// extension
+    ///
+    /// A `calendar` option on the message takes precedence.  See
+    /// [`FluentDateTimeOptions::set_calendar`].
+    pub fn with_calendar(mut self, calendar: unicode::Value) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Default hour cycle for the bundle, standing in for the `-u-hc`
+    /// extension
+    ///
+    /// An `hourCycle` option on the message takes precedence.  See
+    /// [`FluentDateTimeOptions::set_hour_cycle`].
+    pub fn with_hour_cycle(mut self, hour_cycle: HourCycle) -> Self {
+        self.hour_cycle = Some(hour_cycle);
+        self
+    }
+
+    /// What to render for datetimes that can't be formatted, and
+    /// [`DATETIME`] calls that can't be evaluated
+    ///
+    /// A policy set on the datetime's own options takes precedence.  See
+    /// [`ErrorPolicy`].
+    pub fn with_error_policy(mut self, error_policy: ErrorPolicy) -> Self {
+        self.error_policy = Some(error_policy);
+        self
+    }
+
+    /// What to render for [`DATETIME`] calls whose argument is missing
+    /// or isn't a datetime
+    ///
+    /// See [`ArgumentFallback`].
+    pub fn with_argument_fallback(mut self, argument_fallback: ArgumentFallback) -> Self {
+        self.argument_fallback = Some(argument_fallback);
+        self
+    }
+
+    /// Renders custom values [`DATETIME`] doesn't know as they render
+    /// themselves, instead of failing
+    ///
+    /// This is for bundles shared with other crates, whose date types
+    /// translators may wrap in `DATETIME` anyway.  Such calls are still
+    /// reported, and their options are ignored.  This takes precedence
+    /// over the [`ArgumentFallback`], which still applies to other
+    /// arguments.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use fluent_datetime::fluent_bundle::types::FluentType;
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+    /// use fluent_datetime::{BundleExt, DateTimeSupport};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Stardate(f64);
+    ///
+    /// impl FluentType for Stardate {
+    ///     fn duplicate(&self) -> Box<dyn FluentType + Send> {
+    ///         Box::new(self.clone())
+    ///     }
+    ///     fn as_string(&self, _: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+    ///         format!("stardate {}", self.0).into()
+    ///     }
+    ///     fn as_string_threadsafe(
+    ///         &self,
+    ///         _: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ///     ) -> Cow<'static, str> {
+    ///         format!("stardate {}", self.0).into()
+    ///     }
+    /// }
+    ///
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.set_use_isolating(false);
+    /// bundle.add_datetime_support_with(DateTimeSupport::new().with_custom_passthrough())?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "log = Captain's log, {DATETIME($date, dateStyle: \"long\")}".to_string(),
+    /// ).unwrap()).unwrap();
+    /// let log = bundle.get_message("log").unwrap().value().unwrap();
+    /// let stardate = FluentValue::Custom(Box::new(Stardate(41153.7)));
+    /// let args = FluentArgs::from_iter([("date", stardate)]);
+    /// let mut errors = vec![];
+    /// assert_eq!(
+    ///     bundle.format_pattern_reporting(log, Some(&args), &mut errors),
+    ///     "Captain's log, stardate 41153.7"
+    /// );
+    /// assert_eq!(errors.len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_custom_passthrough(mut self) -> Self {
+        self.custom_passthrough = true;
+        self
+    }
+
+    /// House style for the bundle, used for the options that neither the
+    /// datetime nor the [`DATETIME`] call set
+    ///
+    /// The date and time styles go together, so a call asking for a time
+    /// style doesn't get a default date style as well.
+    ///
+    /// Only datetimes passed through [`DATETIME`] get the defaults.  Fluent
+    /// renders a bare `{$date}` without calling any function of the
+    /// bundle, so it keeps the options of the value; write
+    /// `{DATETIME($date)}` for the house style.
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, FluentDateTime, FluentDateTimeOptions, HourCycle};
+    /// use icu_calendar::DateTime;
+    /// use icu_datetime::options::length;
+    ///
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.add_datetime_support_with_defaults(
+    ///     FluentDateTimeOptions::builder()
+    ///         .date_style(length::Date::Medium)
+    ///         .hour_cycle(HourCycle::H23)
+    ///         .build(),
+    /// )?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "today = {DATETIME($date)}
+    /// now = {DATETIME($date, timeStyle: \"short\")}
+    /// bare = {$date}".to_string(),
+    /// ).unwrap()).unwrap();
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let args = FluentArgs::from_iter([("date", FluentDateTime::from(datetime))]);
+    /// let mut errors = vec![];
+    /// let mut format = |id| {
+    ///     let pattern = bundle.get_message(id).unwrap().value().unwrap();
+    ///     bundle.format_pattern(pattern, Some(&args), &mut errors).into_owned()
+    /// };
+    /// assert_eq!(format("today"), "Nov 9, 1989");
+    /// assert_eq!(format("now"), "23:30");
+    /// // Not through DATETIME, so without the defaults
+    /// assert_eq!(format("bare"), "11/9/89");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_defaults(mut self, defaults: FluentDateTimeOptions) -> Self {
+        self.defaults = Some(defaults);
+        self
+    }
+
+    /// Display datetimes in the [system time zone](system_timezone), when
+    /// messages don't give one
+    ///
+    /// This sets the time zone of the [defaults](Self::with_defaults), so
+    /// call it after `with_defaults`.  Nothing changes if the system time
+    /// zone can't be determined.
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, DateTimeSupport};
+    ///
+    /// let mut bundle = FluentBundle::<FluentResource>::new(vec!["en-US".parse()?]);
+    /// bundle.add_datetime_support_with(DateTimeSupport::new().with_system_time_zone())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "system-timezone")]
+    pub fn with_system_time_zone(mut self) -> Self {
+        if let Some(time_zone) = system_timezone() {
+            self.defaults.get_or_insert_with(Default::default).time_zone = Some(time_zone);
+        }
+        self
+    }
+
+    /// Send warnings about the arguments [`DATETIME`] ignores to `sink`
+    ///
+    /// Unknown options, often typos, and extra positional arguments are
+    /// otherwise silently ignored.  Each warning is sent once per pattern
+    /// formatted with [`BundleExt::format_pattern_reporting`], that is
+    /// once per message or attribute.  Plain `format_pattern` doesn't say
+    /// which pattern is formatted, so there each warning is sent once for
+    /// all the patterns it formats.  Bundles sharing this configuration
+    /// share the record of sent warnings, which is cleared once it holds
+    /// a thousand or so, so long-running applications may see a warning
+    /// again.
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, DateTimeSupport, FluentDateTime, Warning};
+    /// use icu_calendar::DateTime;
+    ///
+    /// let warnings = Arc::new(Mutex::new(vec![]));
+    /// let sink = warnings.clone();
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.add_datetime_support_with(DateTimeSupport::new().with_warnings(move |warning| {
+    ///     sink.lock().unwrap().push(warning.clone());
+    /// }))?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "today = {DATETIME($date, datestyle: \"long\")}".to_string(),
+    /// ).unwrap()).unwrap();
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let pattern = bundle.get_message("today").unwrap().value().unwrap();
+    /// for _ in 0..2 {
+    ///     let mut errors = vec![];
+    ///     bundle.format_pattern_reporting(
+    ///         pattern,
+    ///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime.clone()))])),
+    ///         &mut errors,
+    ///     );
+    /// }
+    /// assert_eq!(
+    ///     *warnings.lock().unwrap(),
+    ///     [Warning::IgnoredOption("datestyle".to_string())]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_warnings(mut self, sink: impl Fn(&Warning) + Send + Sync + 'static) -> Self {
+        self.warnings = Some(warning::Warnings::new(sink));
+        self
+    }
+
+    /// Share formatters with other bundles
+    ///
+    /// Only datetimes passed through [`DATETIME`] use the cache, bare
+    /// `{$date}` placeables don't.  See [`FormatterCache`].
+    pub fn with_cache(mut self, cache: FormatterCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Load locale data for this bundle from an [`AnyProvider`]
+    ///
+    /// This isolates the bundle from the process-wide data source, for
+    /// example to serve each tenant of a server its own CLDR data; swap
+    /// data by building a new bundle.  Only datetimes passed through
+    /// [`DATETIME`] use this provider.
+    ///
+    /// [`AnyProvider`]: icu_provider::AnyProvider
+    pub fn with_any_provider(
+        mut self,
+        provider: Arc<dyn icu_provider::AnyProvider + Send + Sync>,
+    ) -> Self {
+        self.provider = Some(SharedProvider::Any(provider));
+        self
+    }
+
+    /// Load locale data for this bundle from a [`BufferProvider`]
+    ///
+    /// See [`with_any_provider`](Self::with_any_provider).
+    ///
+    /// [`BufferProvider`]: icu_provider::BufferProvider
+    #[cfg(feature = "buffer_provider")]
+    pub fn with_buffer_provider(
+        mut self,
+        provider: Arc<dyn icu_provider::BufferProvider + Send + Sync>,
+    ) -> Self {
+        self.provider = Some(SharedProvider::Buffer(provider));
+        self
+    }
+}
+
+/// Picks the Fluent functions to register on a bundle
+///
+/// Pass this to [`BundleExt::add_intl_support`] for the functions
+/// [`BundleExt::add_datetime_support`] leaves out, [`WEEKDAY`] and
+/// [`DURATION`], or to pick functions around those the translations
+/// already have.  Nothing is selected at first; the functions share the
+/// configuration of [`with_support`](Self::with_support).
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, FluentDuration, IntlSupport};
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_datetime_support()?;
+/// bundle.add_intl_support(IntlSupport::new().with_duration())?;
+/// // WEEKDAY is free for the application's own function
+/// bundle.add_function("WEEKDAY", |_, _| "weekday".into())?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "flight = {DURATION($duration)} on {WEEKDAY($day)}".to_string(),
+/// ).unwrap()).unwrap();
+/// let flight = bundle.get_message("flight").unwrap().value().unwrap();
+/// let mut args = FluentArgs::new();
+/// args.set("duration", FluentDuration::from_secs(5400));
+/// args.set("day", 1);
+/// assert_eq!(bundle.format_pattern(flight, Some(&args), &mut vec![]), "1 hr, 30 min on weekday");
+///
+/// // DATETIME is already there
+/// assert!(bundle.add_intl_support(IntlSupport::all()).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IntlSupport {
+    support: DateTimeSupport,
+    datetime: bool,
+    weekday: bool,
+    duration: bool,
+}
+
+impl IntlSupport {
+    /// No functions, to add with the other methods
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every function
+    pub fn all() -> Self {
+        Self::new().with_datetime().with_weekday().with_duration()
+    }
+
+    /// Registers [`DATETIME`]
+    pub fn with_datetime(mut self) -> Self {
+        self.datetime = true;
+        self
+    }
+
+    /// Registers [`WEEKDAY`]
+    pub fn with_weekday(mut self) -> Self {
+        self.weekday = true;
+        self
+    }
+
+    /// Registers [`DURATION`]
+    pub fn with_duration(mut self) -> Self {
+        self.duration = true;
+        self
+    }
+
+    /// Configures the functions, see [`DateTimeSupport`]
+    pub fn with_support(mut self, support: DateTimeSupport) -> Self {
+        self.support = support;
+        self
     }
 }
 
@@ -420,15 +3331,232 @@ pub fn DATETIME<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> Fluen
 pub trait BundleExt {
     /// Registers the [`DATETIME`] function
     ///
-    /// Call this on a [`FluentBundle`].
+    /// Call this on a [`FluentBundle`].  [`WEEKDAY`] and [`DURATION`] are
+    /// opt-in, see [`add_intl_support`](Self::add_intl_support).
+    ///
+    /// Datetimes formatted through [`DATETIME`] fall back along the bundle
+    /// locales when locale data is missing for the first one:
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, FluentDateTime};
+    /// use icu_calendar::DateTime;
+    ///
+    /// // No Swiss German data in ICU
+    /// let mut bundle = FluentBundle::new(vec!["gsw".parse()?, "de".parse()?, "en".parse()?]);
+    /// bundle.add_datetime_support()?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "today = {DATETIME($date, dateStyle: \"long\")}".to_string(),
+    /// ).unwrap()).unwrap();
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut errors = vec![];
+    /// assert_eq!(
+    ///     bundle.format_pattern(
+    ///         bundle.get_message("today").unwrap().value().unwrap(),
+    ///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime))])),
+    ///         &mut errors),
+    ///     "9. November 1989"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn add_datetime_support(&mut self) -> Result<(), FluentDateTimeError>;
+
+    /// Registers some of the functions of this crate, see [`IntlSupport`]
+    ///
+    /// [`add_datetime_support`](Self::add_datetime_support) only registers
+    /// [`DATETIME`].  Functions are added in the order [`DATETIME`],
+    /// [`WEEKDAY`], [`DURATION`], and those added before a name clash
+    /// stay registered.
+    fn add_intl_support(&mut self, intl: IntlSupport) -> Result<(), FluentDateTimeError>;
+
+    /// Registers the [`DATETIME`] function, accepting additional input types
+    ///
+    /// See [`DateTimeExtractors`].
+    fn add_datetime_support_with_extractors(
+        &mut self,
+        extractors: DateTimeExtractors,
+    ) -> Result<(), FluentDateTimeError>;
+
+    /// Registers the [`DATETIME`] function, with the given configuration
+    ///
+    /// See [`DateTimeSupport`].
+    fn add_datetime_support_with(
+        &mut self,
+        support: DateTimeSupport,
+    ) -> Result<(), FluentDateTimeError>;
+
+    /// Registers the [`DATETIME`] function, loading locale data from `provider`
+    ///
+    /// See [`DateTimeSupport::with_any_provider`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, FluentDateTime};
+    /// use icu_calendar::DateTime;
+    /// use icu_provider_adapters::empty::EmptyDataProvider;
+    ///
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.add_datetime_support_with_provider(Arc::new(EmptyDataProvider::new()))?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "today = {DATETIME($date)}".to_string(),
+    /// ).unwrap()).unwrap();
+    ///
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut errors = vec![];
+    /// // No data in this provider
+    /// assert_eq!(
+    ///     bundle.format_pattern(
+    ///         bundle.get_message("today").unwrap().value().unwrap(),
+    ///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime))])),
+    ///         &mut errors),
+    ///     "1989-11-09T23:30:00"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn add_datetime_support_with_provider(
+        &mut self,
+        provider: Arc<dyn icu_provider::AnyProvider + Send + Sync>,
+    ) -> Result<(), FluentDateTimeError>;
+
+    /// Registers the [`DATETIME`] function, with a house style for the
+    /// options neither the datetime nor the call set
+    ///
+    /// Bare `{$date}` placeables don't go through [`DATETIME`] and keep
+    /// the options of the value.  See [`DateTimeSupport::with_defaults`].
+    fn add_datetime_support_with_defaults(
+        &mut self,
+        defaults: FluentDateTimeOptions,
+    ) -> Result<(), FluentDateTimeError>;
+
+    /// Formats a pattern, reporting datetime failures in `errors`
+    ///
+    /// This is [`FluentBundle::format_pattern`], except that failures to
+    /// format datetimes (invalid [`DATETIME`] arguments, missing locale
+    /// data…) are added to `errors` like other resolver errors.  The
+    /// output is unchanged; failed datetimes are still rendered using
+    /// the usual fallbacks.
+    ///
+    /// Fluent's error types don't have room for custom errors, so these
+    /// are reported as [`ResolverError::NoValue`](fluent_bundle::resolver::ResolverError::NoValue)
+    /// with a description starting with `DATETIME:`.
+    ///
+    /// ```
+    /// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+    /// use fluent_datetime::{BundleExt, FluentDateTime};
+    /// use icu_calendar::DateTime;
+    ///
+    /// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+    /// bundle.add_datetime_support()?;
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "now = Now is {DATETIME($date, calendar: \"nonsense\")}".to_string(),
+    /// ).unwrap()).unwrap();
     ///
-    fn add_datetime_support(&mut self) -> Result<(), FluentError>;
+    /// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+    /// let mut errors = vec![];
+    /// assert_eq!(
+    ///     bundle.format_pattern_reporting(
+    ///         bundle.get_message("now").unwrap().value().unwrap(),
+    ///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime))])),
+    ///         &mut errors),
+    ///     "Now is \u{2068}1989-11-09T23:30:00\u{2069}"
+    /// );
+    /// assert_eq!(errors.len(), 1);
+    ///
+    /// // Invalid options are reported, and left out
+    /// bundle.add_resource(FluentResource::try_new(
+    ///     "today = {DATETIME($date, dateStyle: \"fulll\", timeStyle: \"short\")}".to_string(),
+    /// ).unwrap()).unwrap();
+    /// errors.clear();
+    /// assert_eq!(
+    ///     bundle.format_pattern_reporting(
+    ///         bundle.get_message("today").unwrap().value().unwrap(),
+    ///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime))])),
+    ///         &mut errors),
+    ///     "11:30\u{202f}PM"
+    /// );
+    /// assert_eq!(
+    ///     errors[0].to_string(),
+    ///     "Resolver error: No value: DATETIME: invalid value \"fulll\" for option dateStyle"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn format_pattern_reporting<'bundle>(
+        &'bundle self,
+        pattern: &'bundle ast::Pattern<&'bundle str>,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) -> Cow<'bundle, str>;
 }
 
-impl<R, M> BundleExt for FluentBundle<R, M> {
-    fn add_datetime_support(&mut self) -> Result<(), FluentError> {
-        self.add_function("DATETIME", DATETIME)?;
-        //self.set_formatter(Some(datetime_formatter));
+impl<R: Borrow<FluentResource>, M: MemoizerKind> BundleExt for FluentBundle<R, M> {
+    fn add_datetime_support(&mut self) -> Result<(), FluentDateTimeError> {
+        self.add_datetime_support_with(DateTimeSupport::new())
+    }
+
+    fn add_datetime_support_with_extractors(
+        &mut self,
+        extractors: DateTimeExtractors,
+    ) -> Result<(), FluentDateTimeError> {
+        self.add_datetime_support_with(DateTimeSupport::new().with_extractors(extractors))
+    }
+
+    fn add_intl_support(&mut self, intl: IntlSupport) -> Result<(), FluentDateTimeError> {
+        let mut support = intl.support;
+        support.locales = self.locales.as_slice().into();
+        if intl.datetime {
+            let support = support.clone();
+            self.add_function("DATETIME", move |positional, named| {
+                datetime_with_support(positional, named, &support)
+            })?;
+        }
+        if intl.weekday {
+            let support = support.clone();
+            self.add_function("WEEKDAY", move |positional, named| {
+                weekday::weekday_with_support(positional, named, &support)
+            })?;
+        }
+        if intl.duration {
+            self.add_function("DURATION", move |positional, named| {
+                duration::duration_with_support(positional, named, &support)
+            })?;
+        }
         Ok(())
     }
+
+    fn add_datetime_support_with(
+        &mut self,
+        support: DateTimeSupport,
+    ) -> Result<(), FluentDateTimeError> {
+        self.add_intl_support(IntlSupport::new().with_datetime().with_support(support))
+    }
+
+    fn add_datetime_support_with_provider(
+        &mut self,
+        provider: Arc<dyn icu_provider::AnyProvider + Send + Sync>,
+    ) -> Result<(), FluentDateTimeError> {
+        self.add_datetime_support_with(DateTimeSupport::new().with_any_provider(provider))
+    }
+
+    fn add_datetime_support_with_defaults(
+        &mut self,
+        defaults: FluentDateTimeOptions,
+    ) -> Result<(), FluentDateTimeError> {
+        self.add_datetime_support_with(DateTimeSupport::new().with_defaults(defaults))
+    }
+
+    fn format_pattern_reporting<'bundle>(
+        &'bundle self,
+        pattern: &'bundle ast::Pattern<&'bundle str>,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) -> Cow<'bundle, str> {
+        let mut bundle_errors = vec![];
+        let formatted = report::collect(errors, pattern, || {
+            self.format_pattern(pattern, args, &mut bundle_errors)
+        });
+        errors.extend(bundle_errors);
+        formatted
+    }
 }
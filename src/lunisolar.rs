@@ -0,0 +1,246 @@
+//! Months of the Chinese and Dangi calendars
+//!
+//! ICU 1.x numbers months by their position in the year, so the months
+//! after a leap month come out one too high, and writes leap months with
+//! a fixed prefix (閏 or 윤) whatever the locale.  Formatting goes
+//! through [`ByCode`], which numbers months by their code, and leap
+//! months are then rewritten with the locale's CLDR leap month pattern.
+
+use std::fmt;
+
+use icu_calendar::types::{DayOfMonth, DayOfYearInfo, FormattableMonth, FormattableYear};
+use icu_calendar::types::{IsoHour, IsoMinute, IsoSecond, IsoWeekday, MonthCode, NanoSecond};
+use icu_calendar::{AnyCalendarKind, Date, Iso};
+use icu_datetime::input::{DateInput, DateTimeInput, IsoTimeInput};
+use icu_locid::subtags::{language, region, script};
+use icu_provider::{DataLocale, DataPayload};
+
+use crate::calendar;
+use crate::names::SymbolsMarker;
+use crate::provider::Provider;
+use crate::LoadError;
+
+/// A leap month pattern, as the text around the month
+type Pattern = (&'static str, &'static str);
+
+/// What rewriting the leap months of a locale takes
+pub(crate) struct LeapMonths {
+    /// The month names ICU picks from
+    symbols: DataPayload<SymbolsMarker>,
+    /// The prefix ICU writes before the names of leap months
+    marker: &'static str,
+    /// The patterns for month names and numbers
+    text: Pattern,
+    numeric: Pattern,
+}
+
+impl LeapMonths {
+    /// The leap months of the locale's calendar, if it has some
+    pub(crate) fn try_new(
+        provider: Provider,
+        locale: &DataLocale,
+    ) -> Result<Option<Self>, LoadError> {
+        let (kind, _) = calendar::resolve(locale)?;
+        let marker = match kind {
+            AnyCalendarKind::Chinese => "閏",
+            AnyCalendarKind::Dangi => "윤",
+            _ => return Ok(None),
+        };
+        let (text, numeric) = patterns(locale);
+        Ok(Some(Self {
+            symbols: calendar::load_symbols(provider, locale)?,
+            marker,
+            text,
+            numeric,
+        }))
+    }
+
+    /// Formats `value` with `format`, fixing up its month
+    pub(crate) fn write_to<T: DateTimeInput, W: fmt::Write + ?Sized>(
+        &self,
+        value: &T,
+        format: impl Fn(&ByCode<'_, T>) -> String,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let Some((code, (number, leap))) = value
+            .month()
+            .and_then(|month| Some((month.code, month.code.parsed()?)))
+        else {
+            return sink.write_str(&format(&ByCode::new(value, None)));
+        };
+        let s = format(&ByCode::new(value, Some(number.into())));
+        if !leap {
+            return sink.write_str(&s);
+        }
+        // A month name, after ICU's prefix
+        if let Some(i) = s.find(self.marker) {
+            let rest = &s[i + self.marker.len()..];
+            if let Some(name) = self
+                .names(code)
+                .filter(|name| rest.starts_with(name))
+                .max_by_key(|name| name.len())
+            {
+                sink.write_str(&s[..i])?;
+                return write_leap(sink, self.text, name, &rest[name.len()..]);
+            }
+        }
+        // A month number, found where changing it changes the output
+        let other = format(&ByCode::new(value, Some(u32::from(number) % 12 + 1)));
+        match number_span(&s, &other) {
+            Some((start, end)) => {
+                sink.write_str(&s[..start])?;
+                write_leap(sink, self.numeric, &s[start..end], &s[end..])
+            }
+            None => sink.write_str(&s),
+        }
+    }
+
+    /// The names ICU may write for a leap month, in any width
+    fn names(&self, code: MonthCode) -> impl Iterator<Item = &str> {
+        let months = &self.symbols.get().months;
+        let base = code.get_normal_if_leap().unwrap_or(code);
+        let format = [
+            &months.format.wide,
+            &months.format.abbreviated,
+            &months.format.narrow,
+        ];
+        let standalone = months.stand_alone.iter().flat_map(|s| {
+            [s.wide.as_ref(), s.abbreviated.as_ref(), s.narrow.as_ref()]
+                .into_iter()
+                .flatten()
+        });
+        format
+            .into_iter()
+            .chain(standalone)
+            .filter_map(move |symbols| symbols.get(base))
+    }
+}
+
+fn write_leap<W: fmt::Write + ?Sized>(
+    sink: &mut W,
+    (before, after): Pattern,
+    month: &str,
+    rest: &str,
+) -> fmt::Result {
+    sink.write_str(before)?;
+    sink.write_str(month)?;
+    sink.write_str(after)?;
+    sink.write_str(rest)
+}
+
+/// The digits that differ between two outputs
+fn number_span(s: &str, other: &str) -> Option<(usize, usize)> {
+    let prefix = s
+        .char_indices()
+        .zip(other.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(s.len(), |((i, _), _)| i);
+    let suffix = s[prefix..]
+        .chars()
+        .rev()
+        .zip(other[prefix.min(other.len())..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+    let (mut start, mut end) = (prefix, s.len() - suffix);
+    if start >= end {
+        return None;
+    }
+    while let Some(c) = s[..start].chars().next_back().filter(|c| c.is_numeric()) {
+        start -= c.len_utf8();
+    }
+    while let Some(c) = s[end..].chars().next().filter(|c| c.is_numeric()) {
+        end += c.len_utf8();
+    }
+    s[start..end]
+        .chars()
+        .all(char::is_numeric)
+        .then_some((start, end))
+}
+
+/// The CLDR leap month patterns of a locale, for names and numbers
+fn patterns(locale: &DataLocale) -> (Pattern, Pattern) {
+    let lang = locale.language();
+    let traditional = locale.script() == Some(script!("Hant"))
+        || [region!("TW"), region!("HK"), region!("MO")]
+            .into_iter()
+            .any(|r| locale.region() == Some(r));
+    if lang == language!("zh") && !traditional {
+        (("闰", ""), ("闰", ""))
+    } else if lang == language!("zh") || lang == language!("yue") || lang == language!("ja") {
+        (("閏", ""), ("閏", ""))
+    } else if lang == language!("ko") {
+        (("윤", ""), ("윤", ""))
+    } else if lang == language!("vi") {
+        (("", " Nhuận"), ("", "N"))
+    } else {
+        (("", "bis"), ("", "bis"))
+    }
+}
+
+/// A date whose months are numbered by their code, leap months sharing
+/// the number of the month they follow
+pub(crate) struct ByCode<'a, T> {
+    inner: &'a T,
+    ordinal: Option<u32>,
+}
+
+impl<'a, T> ByCode<'a, T> {
+    fn new(inner: &'a T, ordinal: Option<u32>) -> Self {
+        Self { inner, ordinal }
+    }
+}
+
+impl<T: DateInput> DateInput for ByCode<'_, T> {
+    type Calendar = T::Calendar;
+
+    fn year(&self) -> Option<FormattableYear> {
+        self.inner.year()
+    }
+
+    fn month(&self) -> Option<FormattableMonth> {
+        let month = self.inner.month()?;
+        Some(FormattableMonth {
+            ordinal: self.ordinal.unwrap_or(month.ordinal),
+            code: month.code,
+        })
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        self.inner.day_of_month()
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        self.inner.iso_weekday()
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        self.inner.day_of_year_info()
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.inner.any_calendar_kind()
+    }
+
+    fn to_iso(&self) -> Date<Iso> {
+        self.inner.to_iso()
+    }
+}
+
+impl<T: IsoTimeInput> IsoTimeInput for ByCode<'_, T> {
+    fn hour(&self) -> Option<IsoHour> {
+        self.inner.hour()
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        self.inner.minute()
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        self.inner.second()
+    }
+
+    fn nanosecond(&self) -> Option<NanoSecond> {
+        self.inner.nanosecond()
+    }
+}
@@ -0,0 +1,74 @@
+//! The `datetime!` macro
+
+use icu_calendar::DateTime;
+
+use crate::FluentDateTime;
+
+/// A [`FluentDateTime`] from a literal ISO 8601 date and time
+///
+/// The time is optional, and so are its seconds.  Invalid dates and times
+/// are compile errors.
+///
+/// ```
+/// use fluent_datetime::datetime;
+///
+/// let datetime = datetime!(1989-11-09 23:30);
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11/9/89");
+/// assert_eq!((datetime.hour(), datetime.minute()), (23, 30));
+///
+/// assert_eq!(datetime!(1989-11-09 23:30:00), datetime);
+/// assert_eq!(datetime!(2000-02-29).day(), 29);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// let datetime = fluent_datetime::datetime!(1989-02-30);
+/// ```
+#[macro_export]
+macro_rules! datetime {
+    ($year:literal - $month:literal - $day:literal $hour:literal : $minute:literal : $second:literal) => {{
+        const {
+            assert!(
+                $crate::__macro::is_valid($year, $month, $day, $hour, $minute, $second),
+                "invalid datetime"
+            )
+        };
+        $crate::__macro::datetime($year, $month, $day, $hour, $minute, $second)
+    }};
+    ($year:literal - $month:literal - $day:literal $hour:literal : $minute:literal) => {
+        $crate::datetime!($year - $month - $day $hour : $minute : 0)
+    };
+    ($year:literal - $month:literal - $day:literal) => {
+        $crate::datetime!($year - $month - $day 0 : 0 : 0)
+    };
+}
+
+#[doc(hidden)]
+pub mod __macro {
+    use super::*;
+
+    pub const fn is_valid(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> bool {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return false,
+        };
+        day >= 1 && day <= days && hour < 24 && minute < 60 && second < 60
+    }
+
+    pub fn datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> FluentDateTime {
+        DateTime::try_new_iso_datetime(year, month, day, hour, minute, second)
+            .expect("Checked at compile time")
+            .into()
+    }
+}
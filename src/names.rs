@@ -0,0 +1,210 @@
+//! Localized month and weekday names, for date pickers
+
+use icu_calendar::types::IsoWeekday;
+use icu_datetime::options::components;
+use icu_datetime::provider::calendar::{months, weekdays, DateSymbolsV1};
+use icu_provider::{DataMarker, DataPayload};
+
+use crate::provider::{Fallback, Provider};
+use crate::{calendar, FluentDateTimeOptions, LoadError};
+
+/// The data of [`DateNames`], whatever the calendar
+pub(crate) struct SymbolsMarker;
+
+impl DataMarker for SymbolsMarker {
+    type Yokeable = DateSymbolsV1<'static>;
+}
+
+/// How long names are, like the `month`, `weekday` and `era` options of
+/// `Intl.DateTimeFormat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameWidth {
+    /// Like "November" and "Thursday"
+    Long,
+    /// Like "Nov" and "Thu"
+    Short,
+    /// Like "N" and "T", which can be ambiguous
+    Narrow,
+}
+
+impl NameWidth {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn to_text(self) -> components::Text {
+        match self {
+            Self::Long => components::Text::Long,
+            Self::Short => components::Text::Short,
+            Self::Narrow => components::Text::Narrow,
+        }
+    }
+}
+
+/// Whether names are for use within a date or on their own
+///
+/// Some languages inflect month names within dates: Polish writes
+/// "9 listopada 1989" but titles a calendar page "listopad".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameContext {
+    /// Within a formatted date
+    Format,
+    /// On their own, like calendar headers
+    Standalone,
+}
+
+/// The month and weekday names of a locale and calendar
+///
+/// These come from the same data as [`DATETIME`](crate::DATETIME): the
+/// calendar is the one the options and locale pick, see
+/// [`FluentDateTimeOptions::set_calendar`], so date picker headers match
+/// formatted dates.
+///
+/// ```
+/// use fluent_datetime::{DateNames, FluentDateTimeOptions, NameContext, NameWidth};
+/// use icu_calendar::types::IsoWeekday;
+/// use icu_locid::extensions::unicode::value;
+///
+/// let options = FluentDateTimeOptions::default();
+/// let names = DateNames::try_new(&"pl".parse()?, &options)?;
+/// let months = names.months(NameWidth::Long, NameContext::Standalone);
+/// assert_eq!(months.len(), 12);
+/// assert_eq!(months[10], "listopad");
+/// assert_eq!(names.months(NameWidth::Long, NameContext::Format)[10], "listopada");
+/// assert_eq!(names.weekday(IsoWeekday::Thursday, NameWidth::Short, NameContext::Format), "czw.");
+///
+/// let mut options = FluentDateTimeOptions::default();
+/// options.set_calendar(Some(value!("hebrew")));
+/// let names = DateNames::try_new(&"en-US".parse()?, &options)?;
+/// let months = names.months(NameWidth::Long, NameContext::Format);
+/// assert_eq!(months[4..8], ["Shevat", "Adar I", "Adar", "Adar II"]);
+/// assert_eq!(names.weekdays(NameWidth::Short, NameContext::Standalone)[0], "Mon");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct DateNames {
+    symbols: DataPayload<SymbolsMarker>,
+}
+
+impl DateNames {
+    /// Loads the names of a locale, in the calendar of the options
+    ///
+    /// Locale data comes from the same source as
+    /// [`FluentDateTimeFormatter::try_new`](crate::FluentDateTimeFormatter::try_new).
+    pub fn try_new(
+        locale: &unic_langid::LanguageIdentifier,
+        options: &FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        let provider = options.provider().ok_or(LoadError::NoProvider)?;
+        Self::try_new_with_provider(provider, locale, options)
+    }
+
+    /// Loads the names of a locale from an [`AnyProvider`]
+    ///
+    /// [`AnyProvider`]: icu_provider::AnyProvider
+    pub fn try_new_with_any_provider(
+        provider: &(impl icu_provider::AnyProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+        options: &FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Any(&provider), locale, options)
+    }
+
+    /// Loads the names of a locale from a [`BufferProvider`]
+    ///
+    /// [`BufferProvider`]: icu_provider::BufferProvider
+    #[cfg(feature = "buffer_provider")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl icu_provider::BufferProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+        options: &FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Buffer(&provider), locale, options)
+    }
+
+    pub(crate) fn try_new_with_provider(
+        provider: Provider,
+        locale: &unic_langid::LanguageIdentifier,
+        options: &FluentDateTimeOptions,
+    ) -> Result<Self, LoadError> {
+        let langid: icu_locid::LanguageIdentifier = locale
+            .to_string()
+            .parse()
+            .map_err(|_| LoadError::InvalidLocale)?;
+        let locale = options.formatter_key().data_locale(&langid);
+        let fallback = Fallback::new(provider);
+        let symbols = calendar::load_symbols(fallback.get(), &locale)?;
+        Ok(Self { symbols })
+    }
+
+    /// The month names, in the order of the year
+    ///
+    /// Calendars with leap months list them too, after the month they
+    /// follow: the Hebrew calendar has Adar in common years, and Adar I
+    /// and Adar II in leap years.
+    pub fn months(&self, width: NameWidth, context: NameContext) -> Vec<&str> {
+        let months = &self.symbols.get().months;
+        let format = match width {
+            NameWidth::Long => &months.format.wide,
+            NameWidth::Short => &months.format.abbreviated,
+            NameWidth::Narrow => &months.format.narrow,
+        };
+        let standalone = months.stand_alone.as_ref().and_then(|s| match width {
+            NameWidth::Long => s.wide.as_ref(),
+            NameWidth::Short => s.abbreviated.as_ref(),
+            NameWidth::Narrow => s.narrow.as_ref(),
+        });
+        let symbols = match context {
+            NameContext::Standalone => standalone.unwrap_or(format),
+            NameContext::Format => format,
+        };
+        match symbols {
+            months::SymbolsV1::SolarTwelve(names) => names.iter().map(|n| &**n).collect(),
+            months::SymbolsV1::Other(names) => names.iter().map(|(_, n)| n).collect(),
+        }
+    }
+
+    /// The weekday names, from Monday to Sunday
+    ///
+    /// See [`WeekInfo::first_weekday`](crate::WeekInfo::first_weekday)
+    /// for the day the locale starts weeks on.
+    pub fn weekdays(&self, width: NameWidth, context: NameContext) -> [&str; 7] {
+        let names = self.weekday_symbols(width, context);
+        // CLDR starts weeks on Sunday
+        std::array::from_fn(|i| &*names.0[(i + 1) % 7])
+    }
+
+    /// The name of a weekday
+    pub fn weekday(&self, weekday: IsoWeekday, width: NameWidth, context: NameContext) -> &str {
+        let names = self.weekday_symbols(width, context);
+        &names.0[weekday as usize % 7]
+    }
+
+    fn weekday_symbols(&self, width: NameWidth, context: NameContext) -> &weekdays::SymbolsV1<'_> {
+        let weekdays = &self.symbols.get().weekdays;
+        let format = match width {
+            NameWidth::Long => &weekdays.format.wide,
+            NameWidth::Short => &weekdays.format.abbreviated,
+            NameWidth::Narrow => &weekdays.format.narrow,
+        };
+        let standalone = weekdays.stand_alone.as_ref().and_then(|s| match width {
+            NameWidth::Long => s.wide.as_ref(),
+            NameWidth::Short => s.abbreviated.as_ref(),
+            NameWidth::Narrow => s.narrow.as_ref(),
+        });
+        match context {
+            NameContext::Standalone => standalone.unwrap_or(format),
+            NameContext::Format => format,
+        }
+    }
+}
+
+impl std::fmt::Debug for DateNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DateNames").finish_non_exhaustive()
+    }
+}
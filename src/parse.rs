@@ -0,0 +1,303 @@
+//! Reading dates as people type them, like "22/11/2033" or "22 nov. 2033"
+
+use icu_calendar::{Date, DateTime, Gregorian, Time};
+use icu_datetime::options::length;
+use icu_datetime::TypedDateFormatter;
+use icu_locid::extensions::unicode::value;
+use icu_provider::DataLocale;
+
+use crate::provider::{self, load, Fallback, Provider};
+use crate::{DateNames, FluentDateTime, FluentDateTimeError, FluentDateTimeOptions};
+use crate::{LoadError, NameContext, NameWidth};
+
+/// A date whose fields are told apart in formatted dates
+const PROBE: (i32, u8, u8) = (2033, 11, 22);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Year,
+    Month,
+    Day,
+}
+
+/// Reads dates typed in the conventions of a locale
+///
+/// Numeric dates are read in the order of the locale's short dates, so
+/// "03/04/2033" is March 4 in the United States and April 3 in the United
+/// Kingdom.  Months can also be written as names or abbreviations, in any
+/// case, and weekday names and the words of the locale's full dates, like
+/// "de" in Spanish, are skipped.  Digits can be the locale's own.
+///
+/// Two-digit years are taken between 1950 and 2049.  A four-digit number
+/// first is read as a year, whatever the locale, and ISO 8601 dates and
+/// datetimes are accepted as they are, see [`FromStr`](std::str::FromStr)
+/// for [`FluentDateTime`].  Dates are read in the Gregorian calendar, as
+/// floating datetimes at midnight.
+///
+/// ```
+/// use fluent_datetime::DateParser;
+///
+/// let us = DateParser::try_new(&"en-US".parse()?)?;
+/// let date = us.parse("03/04/2033")?;
+/// assert_eq!((date.year(), date.month(), date.day()), (2033, 3, 4));
+/// let date = us.parse("Tuesday, Nov 22, 33")?;
+/// assert_eq!((date.year(), date.month(), date.day()), (2033, 11, 22));
+///
+/// let uk = DateParser::try_new(&"en-GB".parse()?)?;
+/// assert_eq!(uk.parse("03/04/2033")?.month(), 4);
+/// assert!(uk.parse("31/02/2033").is_err());
+/// assert!(uk.parse("04/257/2033").is_err());
+///
+/// let es = DateParser::try_new(&"es-ES".parse()?)?;
+/// assert_eq!(es.parse("22 de Noviembre de 2033")?.month(), 11);
+/// let de = DateParser::try_new(&"de-DE".parse()?)?;
+/// assert_eq!(de.parse("22.11.33")?, "2033-11-22".parse()?);
+/// let eg = DateParser::try_new(&"ar-EG".parse()?)?;
+/// assert_eq!(eg.parse("٢٢/١١/٢٠٣٣")?, "2033-11-22".parse()?);
+/// let ja = DateParser::try_new(&"ja-JP".parse()?)?;
+/// assert_eq!(ja.parse("2033年11月22日")?, "2033-11-22".parse()?);
+/// assert_eq!(ja.parse("2033-11-22T10:00")?.hour(), 10);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateParser {
+    // The order of the fields in numeric dates
+    order: [Field; 3],
+    // Lowercase month names and abbreviations, with their month
+    months: Vec<(String, u8)>,
+    // Lowercase words to skip, from weekday names and full dates
+    skipped: Vec<String>,
+}
+
+impl DateParser {
+    /// Loads the date conventions of a locale
+    ///
+    /// Locale data comes from the same source as
+    /// [`FluentDateTimeFormatter::try_new`](crate::FluentDateTimeFormatter::try_new).
+    pub fn try_new(locale: &unic_langid::LanguageIdentifier) -> Result<Self, LoadError> {
+        let provider = provider::default_provider().ok_or(LoadError::NoProvider)?;
+        Self::try_new_with_provider(provider, locale)
+    }
+
+    /// Loads the date conventions of a locale from an [`AnyProvider`]
+    ///
+    /// [`AnyProvider`]: icu_provider::AnyProvider
+    pub fn try_new_with_any_provider(
+        provider: &(impl icu_provider::AnyProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Any(&provider), locale)
+    }
+
+    /// Loads the date conventions of a locale from a [`BufferProvider`]
+    ///
+    /// [`BufferProvider`]: icu_provider::BufferProvider
+    #[cfg(feature = "buffer_provider")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl icu_provider::BufferProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Buffer(&provider), locale)
+    }
+
+    pub(crate) fn try_new_with_provider(
+        provider: Provider,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Self, LoadError> {
+        let mut options = FluentDateTimeOptions::default();
+        options.set_calendar(Some(value!("gregory")));
+        let names = DateNames::try_new_with_provider(provider, locale, &options)?;
+        let mut months = vec![];
+        for width in [NameWidth::Long, NameWidth::Short] {
+            for context in [NameContext::Format, NameContext::Standalone] {
+                for (i, name) in names.months(width, context).into_iter().enumerate() {
+                    let name = name.trim_end_matches('.').to_lowercase();
+                    months.push((name, i as u8 + 1));
+                }
+            }
+        }
+        let mut skipped = vec![];
+        for width in [NameWidth::Long, NameWidth::Short] {
+            for weekday in names.weekdays(width, NameContext::Format) {
+                skipped.extend(words(weekday));
+            }
+        }
+
+        let langid: icu_locid::LanguageIdentifier = locale
+            .to_string()
+            .parse()
+            .map_err(|_| LoadError::InvalidLocale)?;
+        let locale = DataLocale::from(langid);
+        let fallback = Fallback::new(provider);
+        let (year, month, day) = PROBE;
+        let probe = Date::try_new_gregorian_date(year, month, day).expect("Valid date");
+        let format = |length| -> Result<String, LoadError> {
+            let formatter = load!(
+                fallback.get(),
+                TypedDateFormatter::<Gregorian>::try_new_with_length,
+                TypedDateFormatter::<Gregorian>::try_new_with_length_with_any_provider,
+                TypedDateFormatter::<Gregorian>::try_new_with_length_with_buffer_provider,
+                &locale,
+                length,
+            )?;
+            Ok(formatter.format_to_string(&probe))
+        };
+        skipped.extend(
+            words(&format(length::Date::Full)?)
+                .filter(|word| !months.iter().any(|(name, _)| name == word)),
+        );
+        let mut order = vec![];
+        for token in tokens(&format(length::Date::Short)?) {
+            let field = match token {
+                Token::Number(n, _) if n == u32::from(day) => Field::Day,
+                Token::Number(n, _) if n == u32::from(month) => Field::Month,
+                Token::Number(n, _) if n == year as u32 || n == year as u32 % 100 => Field::Year,
+                _ => continue,
+            };
+            if !order.contains(&field) {
+                order.push(field);
+            }
+        }
+        let order = order
+            .try_into()
+            .unwrap_or([Field::Year, Field::Month, Field::Day]);
+        Ok(Self {
+            order,
+            months,
+            skipped,
+        })
+    }
+
+    /// Reads a date, or fails with
+    /// [`FluentDateTimeError::UnrecognizedDate`], or
+    /// [`FluentDateTimeError::YearOutOfRange`] for years outside of
+    /// [`FluentDateTime::YEARS`]
+    pub fn parse(&self, s: &str) -> Result<FluentDateTime, FluentDateTimeError> {
+        match s.trim().parse() {
+            Ok(datetime) => return Ok(datetime),
+            Err(e @ FluentDateTimeError::YearOutOfRange(_)) => return Err(e),
+            Err(_) => (),
+        }
+        let unrecognized = || FluentDateTimeError::UnrecognizedDate(s.to_string());
+        let mut numbers = vec![];
+        let mut month = None;
+        for token in tokens(s) {
+            match token {
+                Token::Number(n, len) => numbers.push((n, len)),
+                Token::Word(word) => {
+                    if let Some(&(_, m)) = self.months.iter().find(|(name, _)| *name == word) {
+                        if month.replace(m).is_some() {
+                            return Err(unrecognized());
+                        }
+                    } else if !self.skipped.contains(&word) {
+                        return Err(unrecognized());
+                    }
+                }
+            }
+        }
+        let (year, month, day) = match (month, numbers.as_slice()) {
+            (Some(month), &[a, b]) => {
+                let a_is_year = match (is_year(a), is_year(b)) {
+                    (true, false) => true,
+                    (false, true) => false,
+                    (false, false) => self.position(Field::Year) < self.position(Field::Day),
+                    (true, true) => return Err(unrecognized()),
+                };
+                let (year, day) = if a_is_year { (a, b) } else { (b, a) };
+                (year, u32::from(month), day.0)
+            }
+            (None, &[(year, 4), (month, _), (day, _)]) => ((year, 4), month, day),
+            (None, &[a, b, c]) => {
+                let field = |field| [a, b, c][self.position(field)];
+                (
+                    field(Field::Year),
+                    field(Field::Month).0,
+                    field(Field::Day).0,
+                )
+            }
+            _ => return Err(unrecognized()),
+        };
+        let year = match year {
+            (year, 1 | 2) if year < 50 => 2000 + year as i32,
+            (year, 1 | 2) => 1900 + year as i32,
+            (year, _) => i32::try_from(year).map_err(|_| unrecognized())?,
+        };
+        if !FluentDateTime::YEARS.contains(&year) {
+            return Err(FluentDateTimeError::YearOutOfRange(year));
+        }
+        let month = u8::try_from(month).map_err(|_| unrecognized())?;
+        let day = u8::try_from(day).map_err(|_| unrecognized())?;
+        let date = Date::try_new_iso_date(year, month, day).map_err(|_| unrecognized())?;
+        Ok(DateTime::new(date, Time::midnight()).into())
+    }
+
+    fn position(&self, field: Field) -> usize {
+        self.order
+            .iter()
+            .position(|&f| f == field)
+            .expect("All fields")
+    }
+}
+
+/// Whether a number alongside a month name can only be a year
+fn is_year((value, len): (u32, usize)) -> bool {
+    len > 2 || value > 31
+}
+
+enum Token {
+    /// A value and its number of digits
+    Number(u32, usize),
+    /// A lowercase word
+    Word(String),
+}
+
+/// Splits text into numbers and words, dropping punctuation and spaces
+fn tokens(s: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if digit_value(c).is_some() {
+            let (mut value, mut len) = (0u32, 0);
+            while let Some(digit) = chars.peek().copied().and_then(digit_value) {
+                value = value.saturating_mul(10).saturating_add(digit);
+                len += 1;
+                chars.next();
+            }
+            tokens.push(Token::Number(value, len));
+        } else if c.is_alphabetic() {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_alphabetic() {
+                    break;
+                }
+                word.extend(c.to_lowercase());
+                chars.next();
+            }
+            tokens.push(Token::Word(word));
+        } else {
+            chars.next();
+        }
+    }
+    tokens
+}
+
+fn words(s: &str) -> impl Iterator<Item = String> {
+    tokens(s).into_iter().filter_map(|token| match token {
+        Token::Word(word) => Some(word),
+        Token::Number(..) => None,
+    })
+}
+
+/// The value of a decimal digit, in the scripts of CLDR numbering systems
+fn digit_value(c: char) -> Option<u32> {
+    // The zero of each script
+    const ZEROS: [u32; 19] = [
+        0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+        0xE50, 0xED0, 0xF20, 0x1040, 0x17E0, 0xFF10,
+    ];
+    let c = u32::from(c);
+    ZEROS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&c))
+        .map(|zero| c - zero)
+}
@@ -0,0 +1,317 @@
+//! Dates without all of their fields, like credit card expiry dates and
+//! birthdays
+
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use fluent_bundle::types::FluentType;
+use fluent_bundle::FluentValue;
+use icu_calendar::{Date, Iso};
+use icu_datetime::options::{components, length};
+
+use crate::{convert, FluentDateTime, FluentDateTimeError, FluentDateTimeOptions};
+use crate::{NameWidth, Semantics};
+
+/// The fields a partial date shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Fields {
+    YearMonth,
+    MonthDay,
+}
+
+impl Fields {
+    /// The components to format for a date style
+    pub(crate) fn to_components(
+        self,
+        date: length::Date,
+        era: Option<NameWidth>,
+    ) -> components::Bag {
+        let mut bag = components::Bag::default();
+        match self {
+            Self::YearMonth => {
+                bag.era = era.map(NameWidth::to_text);
+                bag.year = Some(components::Year::Numeric);
+            }
+            Self::MonthDay => bag.day = Some(components::Day::NumericDayOfMonth),
+        }
+        bag.month = Some(match date {
+            length::Date::Full | length::Date::Long => components::Month::Long,
+            length::Date::Medium => components::Month::Short,
+            _ => components::Month::Numeric,
+        });
+        bag
+    }
+
+    /// Renders as ISO 8601, for when localized formatting fails
+    pub(crate) fn to_iso8601(self, date: &Date<Iso>) -> String {
+        let year = date.year().number;
+        let month = date.month().ordinal;
+        match self {
+            Self::YearMonth if (0..=9999).contains(&year) => format!("{year:04}-{month:02}"),
+            Self::YearMonth => format!("{year:+05}-{month:02}"),
+            Self::MonthDay => format!("--{month:02}-{:02}", date.day_of_month().0),
+        }
+    }
+}
+
+/// A year and month, like a credit card expiry date or a monthly report
+///
+/// This formats as "November 1989" or "11/1989", with the date style for
+/// the length of the month: `long` and `full` write its name, `medium`
+/// abbreviates it and `short`, the default, writes its number.  The time
+/// style, [`WeekNumber`](crate::WeekNumber) and [`Relative`](crate::Relative)
+/// don't apply.
+/// [`DATETIME`](crate::DATETIME) takes year-months, with the same options
+/// as datetimes.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, FluentYearMonth};
+/// use icu_datetime::options::length;
+///
+/// let expiry = FluentYearMonth::try_new(1989, 11)?;
+/// assert_eq!(expiry.format(&"en-US".parse()?).unwrap(), "11/1989");
+/// let expiry = expiry.map_options(|o| o.set_date_style(Some(length::Date::Long)));
+/// assert_eq!(expiry.format(&"en-US".parse()?).unwrap(), "November 1989");
+/// assert_eq!(expiry.format(&"fr-FR".parse()?).unwrap(), "novembre 1989");
+///
+/// let mut bundle = FluentBundle::new(vec!["de-DE".parse()?]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_datetime_support()?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "report = Bericht {DATETIME($month, dateStyle: \"medium\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let report = bundle.get_message("report").unwrap().value().unwrap();
+/// let args = FluentArgs::from_iter([("month", "1989-11".parse::<FluentYearMonth>()?)]);
+/// assert_eq!(bundle.format_pattern(report, Some(&args), &mut vec![]), "Bericht Nov. 1989");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FluentYearMonth {
+    // On the first of the month at midnight, without a time zone
+    datetime: FluentDateTime,
+}
+
+impl FluentYearMonth {
+    /// A month of a year, in the proleptic Gregorian calendar
+    ///
+    /// Fails if the month isn't from 1 to 12.
+    pub fn try_new(year: i32, month: u8) -> Result<Self, FluentDateTimeError> {
+        let datetime = convert::from_fields(year, month, 1, 0, 0, 0, 0)
+            .ok_or_else(|| FluentDateTimeError::InvalidDateTime(format!("{year:04}-{month:02}")))?;
+        Ok(Self::from_datetime(datetime))
+    }
+
+    fn from_datetime(datetime: FluentDateTime) -> Self {
+        let mut datetime = datetime.with_semantics(Semantics::Floating);
+        datetime.options.fields = Some(Fields::YearMonth);
+        Self { datetime }
+    }
+
+    /// The year, numbered like ISO 8601 (year zero is 1 BCE)
+    pub fn year(&self) -> i32 {
+        self.datetime.year()
+    }
+
+    /// The month, from 1 to 12
+    pub fn month(&self) -> u32 {
+        self.datetime.month()
+    }
+
+    /// Changes the formatting options, in a chain
+    ///
+    /// See [`FluentDateTime::map_options`].
+    pub fn map_options(mut self, f: impl FnOnce(&mut FluentDateTimeOptions)) -> Self {
+        f(&mut self.datetime.options);
+        self
+    }
+
+    /// Formats with the attached options, for an explicit locale
+    ///
+    /// See [`FluentDateTime::format`].
+    pub fn format(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<String, FluentDateTimeError> {
+        self.datetime.format(locale)
+    }
+}
+
+/// Parses `1989-11`, as in ISO 8601
+impl FromStr for FluentYearMonth {
+    type Err = FluentDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FluentDateTimeError::InvalidDateTime(s.to_string());
+        let (year, month) = s.rsplit_once('-').ok_or_else(invalid)?;
+        if month.len() != 2 || year.len() < 4 {
+            return Err(invalid());
+        }
+        let year = year.parse().map_err(|_| invalid())?;
+        let month = month.parse().map_err(|_| invalid())?;
+        Self::try_new(year, month).map_err(|_| invalid())
+    }
+}
+
+/// A day of the year, like a birthday or a holiday, without a year
+///
+/// This formats as "November 9" or "11/9", with the date style for the
+/// length of the month, like [`FluentYearMonth`]; the weekday of the
+/// `full` style depends on the year, so it is left out.  The options
+/// that would show a year, `era` and `weekNumber`, are ignored, and
+/// [`DATETIME`](crate::DATETIME) reports them as invalid.  Month-days
+/// are in the Gregorian calendar, whatever the locale's: they fall on a
+/// different day of other calendars each year.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, FluentMonthDay};
+/// use icu_datetime::options::length;
+///
+/// let birthday = FluentMonthDay::try_new(11, 9)?
+///     .map_options(|o| o.set_date_style(Some(length::Date::Long)));
+/// assert_eq!(birthday.format(&"en-US".parse()?).unwrap(), "November 9");
+/// assert_eq!(birthday.format(&"es-ES".parse()?).unwrap(), "9 de noviembre");
+/// assert_eq!(birthday.format(&"fa-IR".parse()?).unwrap(), "۹ نوامبر");
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_datetime_support()?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "leap = Born on {DATETIME($day)}
+/// era = Born on {DATETIME($day, dateStyle: \"medium\", era: \"short\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let args = FluentArgs::from_iter([("day", "--02-29".parse::<FluentMonthDay>()?)]);
+/// let mut errors = vec![];
+/// let leap = bundle.get_message("leap").unwrap().value().unwrap();
+/// assert_eq!(bundle.format_pattern(leap, Some(&args), &mut errors), "Born on 2/29");
+/// let era = bundle.get_message("era").unwrap().value().unwrap();
+/// assert_eq!(bundle.format_pattern_reporting(era, Some(&args), &mut errors), "Born on Feb 29");
+/// assert_eq!(
+///     errors[0].to_string(),
+///     "Resolver error: No value: DATETIME: invalid value \"short\" for option era"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FluentMonthDay {
+    // In 1972, a leap year, at midnight, without a time zone
+    datetime: FluentDateTime,
+}
+
+impl FluentMonthDay {
+    /// A day of a month, in the Gregorian calendar
+    ///
+    /// February 29th is valid.  Fails if the month has no such day.
+    pub fn try_new(month: u8, day: u8) -> Result<Self, FluentDateTimeError> {
+        let mut datetime = convert::from_fields(1972, month, day, 0, 0, 0, 0)
+            .ok_or_else(|| FluentDateTimeError::InvalidDateTime(format!("--{month:02}-{day:02}")))?
+            .with_semantics(Semantics::Floating);
+        datetime.options.fields = Some(Fields::MonthDay);
+        Ok(Self { datetime })
+    }
+
+    /// The month, from 1 to 12
+    pub fn month(&self) -> u32 {
+        self.datetime.month()
+    }
+
+    /// The day of the month, from 1 to 31
+    pub fn day(&self) -> u32 {
+        self.datetime.day()
+    }
+
+    /// Changes the formatting options, in a chain
+    ///
+    /// See [`FluentDateTime::map_options`].
+    pub fn map_options(mut self, f: impl FnOnce(&mut FluentDateTimeOptions)) -> Self {
+        f(&mut self.datetime.options);
+        self
+    }
+
+    /// Formats with the attached options, for an explicit locale
+    ///
+    /// See [`FluentDateTime::format`].
+    pub fn format(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<String, FluentDateTimeError> {
+        self.datetime.format(locale)
+    }
+}
+
+/// Parses `--11-09`, as in ISO 8601, or `11-09`
+impl FromStr for FluentMonthDay {
+    type Err = FluentDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FluentDateTimeError::InvalidDateTime(s.to_string());
+        let (month, day) = s
+            .strip_prefix("--")
+            .unwrap_or(s)
+            .split_once('-')
+            .ok_or_else(invalid)?;
+        if month.len() != 2 || day.len() != 2 {
+            return Err(invalid());
+        }
+        let month = month.parse().map_err(|_| invalid())?;
+        let day = day.parse().map_err(|_| invalid())?;
+        Self::try_new(month, day).map_err(|_| invalid())
+    }
+}
+
+impl FluentType for FluentMonthDay {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        self.datetime.as_string(intls)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.datetime.as_string_threadsafe(intls)
+    }
+}
+
+impl From<FluentMonthDay> for FluentValue<'static> {
+    fn from(value: FluentMonthDay) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+impl FluentType for FluentYearMonth {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        self.datetime.as_string(intls)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.datetime.as_string_threadsafe(intls)
+    }
+}
+
+impl From<FluentYearMonth> for FluentValue<'static> {
+    fn from(value: FluentYearMonth) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+/// The datetime that [`DATETIME`](crate::DATETIME) formats for a partial
+/// date
+pub(crate) fn as_datetime(value: &dyn FluentType) -> Option<&FluentDateTime> {
+    let value = value.as_any();
+    match value.downcast_ref::<FluentYearMonth>() {
+        Some(year_month) => Some(&year_month.datetime),
+        None => Some(&value.downcast_ref::<FluentMonthDay>()?.datetime),
+    }
+}
@@ -0,0 +1,123 @@
+//! What to render when a datetime can't be formatted
+
+use std::sync::Arc;
+
+use fluent_bundle::FluentValue;
+
+/// What to render when a datetime can't be formatted, or a [`DATETIME`]
+/// call can't be evaluated
+///
+/// Without a policy, datetimes that fail to format are rendered as
+/// ISO 8601, [`DATETIME`] calls with invalid options format the datetime
+/// without them, and calls without a datetime render as `{DATETIME()}`,
+/// like failed Fluent function calls.  Failures are reported either way, see
+/// [`BundleExt::format_pattern_reporting`](crate::BundleExt::format_pattern_reporting).
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, DateTimeSupport, ErrorPolicy, FluentDateTime};
+/// use icu_calendar::DateTime;
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support_with(
+///     DateTimeSupport::new().with_error_policy(ErrorPolicy::Placeholder("…".into())),
+/// )?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "today = {DATETIME($date, dateStyle: \"fulll\")}".to_string(),
+/// ).unwrap()).unwrap();
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut errors = vec![];
+/// assert_eq!(
+///     bundle.format_pattern(
+///         bundle.get_message("today").unwrap().value().unwrap(),
+///         Some(&FluentArgs::from_iter([("date", FluentDateTime::from(datetime))])),
+///         &mut errors),
+///     "…"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`DATETIME`]: crate::DATETIME
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorPolicy {
+    /// Render the fixed marker `{DATETIME()}`, the way Fluent renders
+    /// failed function calls
+    ///
+    /// The marker is the same for bare `{$date}` placeables, and no Fluent
+    /// error reaches the caller: failures are only reported, see
+    /// [`BundleExt::format_pattern_reporting`](crate::BundleExt::format_pattern_reporting).
+    Marker,
+    /// Render the datetime as ISO 8601, when there is one
+    Iso8601,
+    /// Render this text instead
+    Placeholder(Arc<str>),
+    /// Panic in debug builds, to catch broken dates in development;
+    /// release builds behave as without a policy
+    DebugPanic,
+}
+
+impl ErrorPolicy {
+    /// Rendering of failed function calls by fluent-bundle
+    pub(crate) const MARKER: &'static str = "{DATETIME()}";
+}
+
+/// What [`DATETIME`] renders when its argument is missing or isn't a
+/// datetime
+///
+/// This takes precedence over the [`ErrorPolicy`] for such calls, whose
+/// default Fluent error rendering, `{DATETIME()}`, says little about what
+/// went wrong.  The failure is still reported.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{ArgumentFallback, BundleExt, DateTimeSupport};
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support_with(
+///     DateTimeSupport::new().with_argument_fallback(ArgumentFallback::Argument("????".into())),
+/// )?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "today = {DATETIME($date)}".to_string(),
+/// ).unwrap()).unwrap();
+/// let pattern = bundle.get_message("today").unwrap().value().unwrap();
+///
+/// let mut args = FluentArgs::new();
+/// args.set("date", "yesterday");
+/// let mut errors = vec![];
+/// assert_eq!(bundle.format_pattern(pattern, Some(&args), &mut errors), "yesterday");
+/// assert_eq!(bundle.format_pattern(pattern, None, &mut errors), "????");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`DATETIME`]: crate::DATETIME
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ArgumentFallback {
+    /// Render this text instead, like `????`
+    Placeholder(Arc<str>),
+    /// Render the argument the way Fluent would without [`DATETIME`], or
+    /// this text if the argument is missing
+    ///
+    /// [`DATETIME`]: crate::DATETIME
+    Argument(Arc<str>),
+}
+
+impl ArgumentFallback {
+    pub(crate) fn render<'a>(&self, argument: Option<&FluentValue<'a>>) -> FluentValue<'a> {
+        match (self, argument) {
+            (
+                Self::Argument(_),
+                Some(
+                    argument @ (FluentValue::String(_)
+                    | FluentValue::Number(_)
+                    | FluentValue::Custom(_)),
+                ),
+            ) => argument.clone(),
+            (Self::Placeholder(placeholder) | Self::Argument(placeholder), _) => {
+                FluentValue::String(placeholder.to_string().into())
+            }
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Where ICU locale data is loaded from
+
+use icu_locid_transform::fallback::LocaleFallbacker;
+#[cfg(feature = "buffer_provider")]
+use icu_provider::BufferProvider;
+use icu_provider::{AnyProvider, DataError, DataLocale};
+use icu_provider_adapters::fallback::LocaleFallbackProvider;
+use icu_timezone::{MetazoneCalculator, TimeZoneError, TimeZoneIdMapper};
+use std::fmt;
+use std::sync::Arc;
+#[cfg(feature = "blob")]
+use {
+    icu_provider::{BufferMarker, DataErrorKind, DataKey, DataRequest, DataResponse},
+    icu_provider_blob::BlobDataProvider,
+    std::{
+        future::Future,
+        io,
+        path::Path,
+        sync::atomic::{AtomicU64, Ordering},
+        sync::{Mutex, RwLock},
+    },
+};
+
+/// The data source used to build ICU formatters
+#[derive(Clone, Copy)]
+pub(crate) enum Provider<'a> {
+    /// The data compiled into the ICU crates
+    #[cfg(feature = "compiled_data")]
+    Compiled,
+    Any(&'a (dyn AnyProvider + 'a)),
+    #[cfg(feature = "buffer_provider")]
+    Buffer(&'a (dyn BufferProvider + 'a)),
+}
+
+/// A [`Provider`] that falls back to parent locales for missing data
+///
+/// Compiled data does this on its own; other providers are wrapped in
+/// a [`LocaleFallbackProvider`], using the provider's fallback data if it
+/// has some.
+#[allow(clippy::large_enum_variant)] // only lives during construction
+pub(crate) enum Fallback<'a> {
+    #[cfg(feature = "compiled_data")]
+    Compiled,
+    Any(LocaleFallbackProvider<&'a (dyn AnyProvider + 'a)>),
+    #[cfg(feature = "buffer_provider")]
+    Buffer(LocaleFallbackProvider<&'a (dyn BufferProvider + 'a)>),
+}
+
+impl<'a> Fallback<'a> {
+    pub(crate) fn new(provider: Provider<'a>) -> Self {
+        match provider {
+            #[cfg(feature = "compiled_data")]
+            Provider::Compiled => Self::Compiled,
+            Provider::Any(p) => Self::Any(LocaleFallbackProvider::new_with_fallbacker(
+                p,
+                fallbacker(LocaleFallbacker::try_new_with_any_provider(&p)),
+            )),
+            #[cfg(feature = "buffer_provider")]
+            Provider::Buffer(p) => Self::Buffer(LocaleFallbackProvider::new_with_fallbacker(
+                p,
+                fallbacker(LocaleFallbacker::try_new_with_buffer_provider(&p)),
+            )),
+        }
+    }
+
+    pub(crate) fn get(&self) -> Provider<'_> {
+        match self {
+            #[cfg(feature = "compiled_data")]
+            Self::Compiled => Provider::Compiled,
+            Self::Any(p) => Provider::Any(p),
+            #[cfg(feature = "buffer_provider")]
+            Self::Buffer(p) => Provider::Buffer(p),
+        }
+    }
+}
+
+fn fallbacker(loaded: Result<LocaleFallbacker, DataError>) -> LocaleFallbacker {
+    loaded.unwrap_or_else(|_| {
+        #[cfg(feature = "compiled_data")]
+        return LocaleFallbacker::new().static_to_owned();
+        #[cfg(not(feature = "compiled_data"))]
+        return LocaleFallbacker::new_without_data();
+    })
+}
+
+/// A provider owned by a bundle, see [`crate::DateTimeSupport::with_any_provider`]
+///
+/// Compared by identity, so formatters are cached per provider.
+#[derive(Clone)]
+pub(crate) enum SharedProvider {
+    Any(Arc<dyn AnyProvider + Send + Sync>),
+    #[cfg(feature = "buffer_provider")]
+    Buffer(Arc<dyn BufferProvider + Send + Sync>),
+}
+
+impl SharedProvider {
+    pub(crate) fn get(&self) -> Provider<'_> {
+        match self {
+            Self::Any(p) => Provider::Any(&**p),
+            #[cfg(feature = "buffer_provider")]
+            Self::Buffer(p) => Provider::Buffer(&**p),
+        }
+    }
+
+    fn addr(&self) -> *const () {
+        match self {
+            Self::Any(p) => Arc::as_ptr(p).cast(),
+            #[cfg(feature = "buffer_provider")]
+            Self::Buffer(p) => Arc::as_ptr(p).cast(),
+        }
+    }
+}
+
+impl PartialEq for SharedProvider {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl Eq for SharedProvider {}
+
+impl std::hash::Hash for SharedProvider {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.addr().hash(state);
+    }
+}
+
+impl fmt::Debug for SharedProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any(_) => f.write_str("SharedProvider::Any(..)"),
+            #[cfg(feature = "buffer_provider")]
+            Self::Buffer(_) => f.write_str("SharedProvider::Buffer(..)"),
+        }
+    }
+}
+
+/// The registered data blobs, tried in order
+#[cfg(feature = "blob")]
+struct Blobs {
+    blobs: RwLock<Vec<BlobDataProvider>>,
+    // Locales loaded or being loaded by load_locale_data_blob
+    locales: Mutex<Vec<unic_langid::LanguageIdentifier>>,
+    // Bumped by every registration, see generation()
+    generation: AtomicU64,
+}
+
+#[cfg(feature = "blob")]
+static BLOBS: Blobs = Blobs {
+    blobs: RwLock::new(Vec::new()),
+    locales: Mutex::new(Vec::new()),
+    generation: AtomicU64::new(0),
+};
+
+#[cfg(feature = "blob")]
+impl BufferProvider for Blobs {
+    fn load_buffer(
+        &self,
+        key: DataKey,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        let blobs = self.blobs.read().unwrap_or_else(|e| e.into_inner());
+        let mut last_error = DataErrorKind::MissingLocale.with_req(key, req);
+        for blob in blobs.iter() {
+            match blob.load_buffer(key, req) {
+                Err(e)
+                    if e.kind == DataErrorKind::MissingLocale
+                        || e.kind == DataErrorKind::MissingDataKey =>
+                {
+                    last_error = e;
+                }
+                result => return result,
+            }
+        }
+        Err(last_error)
+    }
+}
+
+/// Makes a postcard data blob the data source for all datetime formatting
+///
+/// The blob, as produced by `icu4x-datagen --format blob`, is used instead
+/// of compiled data everywhere a provider isn't given explicitly.
+/// Formatters that bundles and [`FormatterCache`](crate::FormatterCache)s
+/// built from earlier data are rebuilt on their next use.  Several blobs can
+/// be registered, for example one per locale; data is looked up in the
+/// order they were registered.
+///
+/// ```
+/// // Not a valid blob
+/// assert!(fluent_datetime::register_data_blob(vec![0; 16]).is_err());
+/// ```
+#[cfg(feature = "blob")]
+pub fn register_data_blob(blob: impl Into<Box<[u8]>>) -> Result<(), DataError> {
+    let provider = BlobDataProvider::try_new_from_blob(blob.into())?;
+    let mut blobs = BLOBS.blobs.write().unwrap_or_else(|e| e.into_inner());
+    blobs.push(provider);
+    BLOBS.generation.fetch_add(1, Ordering::Release);
+    Ok(())
+}
+
+/// Fetches and registers the data blob of a locale, unless already done
+///
+/// This lets web applications download locale data on demand rather
+/// than ship every locale in the WASM binary: await this when the user
+/// picks a locale, before formatting messages in it.  `fetch` is only
+/// called the first time a locale is requested.
+///
+/// Messages formatted in a locale before its data is available fall back
+/// to ISO 8601, or to root locale data if a registered blob has some, and
+/// pick up the locale's data once it is registered.
+///
+/// ```
+/// use std::future::Future;
+/// use std::task::{Context, Poll, Waker};
+///
+/// let locale = "fr".parse()?;
+/// // Usually an HTTP request
+/// let fetch = |_: &_| async { Ok::<_, icu_provider::DataError>(vec![0; 16]) };
+/// let mut load = std::pin::pin!(fluent_datetime::load_locale_data_blob(&locale, fetch));
+/// let Poll::Ready(result) = load.as_mut().poll(&mut Context::from_waker(Waker::noop())) else {
+///     unreachable!()
+/// };
+/// // Not a valid blob
+/// assert!(result.is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "blob")]
+pub async fn load_locale_data_blob<F, Fut, E>(
+    locale: &unic_langid::LanguageIdentifier,
+    fetch: F,
+) -> Result<(), E>
+where
+    F: FnOnce(&unic_langid::LanguageIdentifier) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, E>>,
+    E: From<DataError>,
+{
+    {
+        let mut locales = BLOBS.locales.lock().unwrap_or_else(|e| e.into_inner());
+        if locales.contains(locale) {
+            return Ok(());
+        }
+        locales.push(locale.clone());
+    }
+    let result = match fetch(locale).await {
+        Ok(blob) => register_data_blob(blob).map_err(E::from),
+        Err(e) => Err(e),
+    };
+    if result.is_err() {
+        // Let a later call retry
+        BLOBS
+            .locales
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|l| l != locale);
+    }
+    result
+}
+
+/// Reads a postcard data blob from a file and registers it
+///
+/// See [`register_data_blob`].
+#[cfg(feature = "blob")]
+pub fn register_data_blob_file(path: impl AsRef<Path>) -> io::Result<()> {
+    let blob = std::fs::read(path)?;
+    register_data_blob(blob).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// The provider used when none is given
+///
+/// This is the registered data blobs if any, else compiled data unless the
+/// `compiled_data` feature is disabled.
+pub(crate) fn default_provider() -> Option<Provider<'static>> {
+    #[cfg(feature = "blob")]
+    if !BLOBS
+        .blobs
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_empty()
+    {
+        return Some(Provider::Buffer(&BLOBS));
+    }
+    #[cfg(feature = "compiled_data")]
+    return Some(Provider::Compiled);
+    #[cfg(not(feature = "compiled_data"))]
+    return None;
+}
+
+/// Changes whenever the data of the [`default_provider`] does
+///
+/// Memoized formatters include it in their key, so registering a data
+/// blob doesn't leave them built from the earlier data.
+pub(crate) fn generation() -> u64 {
+    #[cfg(feature = "blob")]
+    return BLOBS.generation.load(Ordering::Acquire);
+    #[cfg(not(feature = "blob"))]
+    return 0;
+}
+
+/// Calls the ICU constructor matching a [`Provider`]
+///
+/// Takes the compiled data, any provider and buffer provider constructors,
+/// followed by the remaining arguments.
+macro_rules! load {
+    ($provider:expr, $compiled:path, $any:path, $buffer:path $(, $arg:expr)* $(,)?) => {
+        match $provider {
+            #[cfg(feature = "compiled_data")]
+            $crate::provider::Provider::Compiled => $compiled($($arg),*),
+            $crate::provider::Provider::Any(p) => $any(&p, $($arg),*),
+            #[cfg(feature = "buffer_provider")]
+            $crate::provider::Provider::Buffer(p) => $buffer(&p, $($arg),*),
+        }
+    };
+}
+pub(crate) use load;
+
+/// Loads the data of a marker from a [`Provider`]
+///
+/// Compiled data is ICU's datetime data; other providers go through the
+/// downcasting or deserializing adapters.
+macro_rules! load_data {
+    ($provider:expr, $marker:ty, $req:expr) => {{
+        use icu_provider::DataProvider;
+        let response: Result<icu_provider::DataResponse<$marker>, icu_provider::DataError> =
+            match $provider {
+                #[cfg(feature = "compiled_data")]
+                $crate::provider::Provider::Compiled => icu_datetime::provider::Baked.load($req),
+                $crate::provider::Provider::Any(p) => {
+                    use icu_provider::AsDowncastingAnyProvider;
+                    p.as_downcasting().load($req)
+                }
+                #[cfg(feature = "buffer_provider")]
+                $crate::provider::Provider::Buffer(p) => {
+                    use icu_provider::AsDeserializingBufferProvider;
+                    p.as_deserializing().load($req)
+                }
+            };
+        response
+    }};
+}
+pub(crate) use load_data;
+
+/// The locale whose datetime data the provider has for `locale`, after
+/// fallback
+///
+/// This is `und` when only root data is available.
+pub(crate) fn resolve_locale(provider: Provider, locale: &DataLocale) -> Option<DataLocale> {
+    let fallback = Fallback::new(provider);
+    use icu_datetime::provider::calendar::TimeSymbolsV1Marker;
+    let req = icu_provider::DataRequest {
+        locale,
+        metadata: Default::default(),
+    };
+    let response = load_data!(fallback.get(), TimeSymbolsV1Marker, req).ok()?;
+    Some(response.metadata.locale.unwrap_or_else(|| locale.clone()))
+}
+
+/// Loads the mapping from IANA time zone identifiers to BCP-47 ones
+pub(crate) fn time_zone_ids(provider: Provider) -> Result<TimeZoneIdMapper, DataError> {
+    match provider {
+        #[cfg(feature = "compiled_data")]
+        Provider::Compiled => Ok(TimeZoneIdMapper::new()),
+        Provider::Any(p) => TimeZoneIdMapper::try_new_with_any_provider(&p),
+        #[cfg(feature = "buffer_provider")]
+        Provider::Buffer(p) => TimeZoneIdMapper::try_new_with_buffer_provider(&p),
+    }
+}
+
+/// Loads the metazone data needed to display zone names
+pub(crate) fn metazones(provider: Provider) -> Result<MetazoneCalculator, DataError> {
+    let metazones = match provider {
+        #[cfg(feature = "compiled_data")]
+        Provider::Compiled => return Ok(MetazoneCalculator::new()),
+        Provider::Any(p) => MetazoneCalculator::try_new_with_any_provider(&p),
+        #[cfg(feature = "buffer_provider")]
+        Provider::Buffer(p) => MetazoneCalculator::try_new_with_buffer_provider(&p),
+    };
+    metazones.map_err(|e| match e {
+        TimeZoneError::Data(e) => e,
+        _ => DataError::custom("Invalid metazone data"),
+    })
+}
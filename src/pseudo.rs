@@ -0,0 +1,102 @@
+//! Pseudo-localization of formatted datetimes
+
+use std::fmt;
+
+/// Pseudo-localization styles for formatted datetimes
+///
+/// Fluent's [`set_transform`](fluent_bundle::FluentBundle::set_transform)
+/// only applies to the text of messages, so in a pseudo-localized build
+/// datetimes would stand out as the only untransformed text.  These styles
+/// transform the formatted datetime in a similar way, while keeping it
+/// readable.
+///
+/// ```
+/// use fluent_datetime::{FluentDateTime, Pseudolocalization};
+/// use icu_calendar::DateTime;
+/// use icu_datetime::options::length;
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut datetime = FluentDateTime::from(datetime);
+/// datetime.options.set_date_style(Some(length::Date::Long));
+///
+/// datetime.options.set_pseudo(Some(Pseudolocalization::Accented));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "[Ƞǿṽḗḿƀḗř 9, 1989]");
+///
+/// datetime.options.set_pseudo(Some(Pseudolocalization::Elongated));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "[Ƞǿǿṽḗḗḿƀḗḗř 9, 1989]");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Pseudolocalization {
+    /// Brackets the output and replaces ASCII letters with accented ones
+    Accented,
+    /// Like `Accented`, also doubling vowels to simulate longer translations
+    Elongated,
+}
+
+/// Pseudo-localizes text on its way to the sink
+///
+/// The opening bracket is only written along with the first text, so
+/// the sink is untouched if formatting fails before writing anything.
+pub(crate) struct Writer<'a, W: ?Sized> {
+    pseudo: Pseudolocalization,
+    sink: &'a mut W,
+    opened: bool,
+}
+
+impl<'a, W: fmt::Write + ?Sized> Writer<'a, W> {
+    pub(crate) fn new(pseudo: Pseudolocalization, sink: &'a mut W) -> Self {
+        Self {
+            pseudo,
+            sink,
+            opened: false,
+        }
+    }
+
+    /// Closes the brackets
+    pub(crate) fn finish(mut self) -> fmt::Result {
+        self.open()?;
+        self.sink.write_char(']')
+    }
+
+    fn open(&mut self) -> fmt::Result {
+        if !self.opened {
+            self.opened = true;
+            self.sink.write_char('[')?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for Writer<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.open()?;
+        for ch in s.chars() {
+            let accented = accent(ch);
+            self.sink.write_char(accented)?;
+            if self.pseudo == Pseudolocalization::Elongated && "aeiouyAEIOUY".contains(ch) {
+                self.sink.write_char(accented)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// The same mapping as fluent-pseudo
+const ACCENTED_UPPER: [char; 26] = [
+    'Ȧ', 'Ɓ', 'Ƈ', 'Ḓ', 'Ḗ', 'Ƒ', 'Ɠ', 'Ħ', 'Ī', 'Ĵ', 'Ķ', 'Ŀ', 'Ḿ', 'Ƞ', 'Ǿ', 'Ƥ', 'Ɋ', 'Ř', 'Ş',
+    'Ŧ', 'Ŭ', 'Ṽ', 'Ẇ', 'Ẋ', 'Ẏ', 'Ẑ',
+];
+const ACCENTED_LOWER: [char; 26] = [
+    'ȧ', 'ƀ', 'ƈ', 'ḓ', 'ḗ', 'ƒ', 'ɠ', 'ħ', 'ī', 'ĵ', 'ķ', 'ŀ', 'ḿ', 'ƞ', 'ǿ', 'ƥ', 'ɋ', 'ř', 'ş',
+    'ŧ', 'ŭ', 'ṽ', 'ẇ', 'ẋ', 'ẏ', 'ẑ',
+];
+
+fn accent(ch: char) -> char {
+    match ch {
+        'A'..='Z' => ACCENTED_UPPER[usize::from(ch as u8 - b'A')],
+        'a'..='z' => ACCENTED_LOWER[usize::from(ch as u8 - b'a')],
+        _ => ch,
+    }
+}
@@ -0,0 +1,507 @@
+//! Relative phrases for datetimes close to a reference time
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use fixed_decimal::FixedDecimal;
+use icu_experimental::relativetime::options::Numeric;
+use icu_experimental::relativetime::{RelativeTimeFormatter, RelativeTimeFormatterOptions};
+use icu_provider::DataLocale;
+use writeable::Writeable;
+
+use crate::provider::{load, Provider};
+use crate::{convert, FluentDateTime};
+
+/// Renders datetimes close to a reference time as relative phrases
+///
+/// Within the threshold, the datetime is written as "yesterday",
+/// "in 2 hours", "3 weeks ago"…; further away, the date and time styles
+/// apply as usual.  The reference time defaults to the time of formatting.
+///
+/// Datetimes are compared as UTC instants.  When a [`TimeZone`](crate::TimeZone)
+/// is set, days, months and years are counted in that zone.
+///
+/// The unit is picked from the distance, unless set with
+/// [`with_unit`](Self::with_unit).  Unlike `Intl.RelativeTimeFormat`,
+/// phrases use words like "yesterday" where the locale has them, unless
+/// [`with_numeric`](Self::with_numeric) asks for numbers.
+///
+/// In a Fluent message, use `relative: "auto"`, optionally with
+/// `relativeThreshold` in seconds (one week by default), `relativeUnit`
+/// and `relativeNumeric`, which take the values of the `unit` and
+/// `numeric` options of `Intl.RelativeTimeFormat`, `relativeRounding`
+/// (`floor`, `round` or `ceil`) and `relativeNowCutoff` in seconds.
+///
+/// ```
+/// use fluent_datetime::{FluentDateTime, Relative, RelativeNumeric, RelativeRounding, RelativeUnit};
+/// use icu_calendar::DateTime;
+/// use std::time::Duration;
+///
+/// let now = DateTime::try_new_iso_datetime(1989, 11, 10, 9, 0, 0).unwrap();
+/// let relative = Relative::auto().relative_to(&FluentDateTime::from(now));
+///
+/// let mut datetime = FluentDateTime::from(DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap());
+/// datetime.options.set_relative(Some(relative));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "9 hours ago");
+///
+/// let mut datetime = FluentDateTime::from(DateTime::try_new_iso_datetime(1989, 11, 8, 12, 0, 0).unwrap());
+/// datetime.options.set_relative(Some(relative));
+/// assert_eq!(datetime.format(&"fr-FR".parse()?).unwrap(), "avant-hier");
+///
+/// datetime.options.set_relative(Some(relative.with_threshold(Duration::from_secs(86_400))));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11/8/89");
+///
+/// datetime.options.set_relative(Some(relative.with_numeric(RelativeNumeric::Always)));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "2 days ago");
+/// datetime.options.set_relative(Some(relative.with_unit(RelativeUnit::Hour)));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "45 hours ago");
+///
+/// let mut datetime = FluentDateTime::from(DateTime::try_new_iso_datetime(1989, 11, 10, 8, 59, 40).unwrap());
+/// datetime.options.set_relative(Some(relative.with_rounding(RelativeRounding::Round)));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "20 seconds ago");
+/// datetime.options.set_relative(Some(relative.with_now_cutoff(Duration::from_secs(30))));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "now");
+/// let numbers = relative.with_numeric(RelativeNumeric::Always);
+/// datetime.options.set_relative(Some(numbers.with_now_cutoff(Duration::from_secs(30))));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "now");
+///
+/// let mut datetime = FluentDateTime::from(DateTime::try_new_iso_datetime(1989, 11, 10, 8, 0, 20).unwrap());
+/// datetime.options.set_relative(Some(relative));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "59 minutes ago");
+/// datetime.options.set_relative(Some(relative.with_rounding(RelativeRounding::Round)));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "1 hour ago");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Relative {
+    threshold: Duration,
+    // Unix seconds, None for the time of formatting
+    reference: Option<i64>,
+    // None to pick the unit from the distance
+    unit: Option<RelativeUnit>,
+    numeric: RelativeNumeric,
+    rounding: RelativeRounding,
+    now_cutoff: Duration,
+}
+
+impl Relative {
+    /// Relative phrases within a week of the time of formatting
+    pub fn auto() -> Self {
+        Self {
+            threshold: Duration::from_secs(7 * 86_400),
+            reference: None,
+            unit: None,
+            numeric: RelativeNumeric::Auto,
+            rounding: RelativeRounding::Floor,
+            now_cutoff: Duration::ZERO,
+        }
+    }
+
+    /// Sets how far from the reference time phrases are used
+    pub fn with_threshold(mut self, threshold: Duration) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Compares to a fixed reference time rather than the time of formatting
+    ///
+    /// The reference is taken to be in UTC.
+    pub fn relative_to(mut self, reference: &FluentDateTime) -> Self {
+        self.reference = Some(convert::to_unix(reference.value()));
+        self
+    }
+
+    /// Counts in a single unit, like "48 hours ago" rather than "2 days ago"
+    ///
+    /// Days, weeks, months and years are counted between calendar days,
+    /// so 9 PM yesterday is "yesterday" in days even less than a day ago.
+    pub fn with_unit(mut self, unit: RelativeUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Sets whether phrases may use words, like "yesterday", or always
+    /// use numbers, like "1 day ago"
+    pub fn with_numeric(mut self, numeric: RelativeNumeric) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets how counts are rounded, like 59 minutes and 40 seconds ago
+    /// being "59 minutes ago" or "1 hour ago"
+    pub fn with_rounding(mut self, rounding: RelativeRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Writes the locale's word for "now" for datetimes closer than
+    /// `cutoff` to the reference time
+    ///
+    /// This is off (zero) by default, for "5 seconds ago".
+    pub fn with_now_cutoff(mut self, cutoff: Duration) -> Self {
+        self.now_cutoff = cutoff;
+        self
+    }
+
+    /// How far from the reference time phrases are used
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// The unit phrases count in, if not picked from the distance
+    pub fn unit(&self) -> Option<RelativeUnit> {
+        self.unit
+    }
+
+    /// Whether phrases may use words instead of numbers
+    pub fn numeric(&self) -> RelativeNumeric {
+        self.numeric
+    }
+
+    /// How counts are rounded
+    pub fn rounding(&self) -> RelativeRounding {
+        self.rounding
+    }
+
+    /// How close to the reference time datetimes are written as "now"
+    pub fn now_cutoff(&self) -> Duration {
+        self.now_cutoff
+    }
+
+    /// Picks the unit and signed count to describe `value`, if within the threshold
+    ///
+    /// `offset` is the UTC offset in seconds days are counted in.  Within
+    /// the "now" cutoff, this is zero seconds.
+    fn select(&self, value: i64, offset: i64) -> Option<(RelativeUnit, i64)> {
+        let reference = match self.reference {
+            Some(reference) => reference,
+            None => now(),
+        };
+        let delta = value.checked_sub(reference)?;
+        if delta.unsigned_abs() > self.threshold.as_secs() {
+            return None;
+        }
+        if delta.unsigned_abs() < self.now_cutoff.as_secs() {
+            return Some((RelativeUnit::Second, 0));
+        }
+        let value_day = (value + offset).div_euclid(86_400);
+        let reference_day = (reference + offset).div_euclid(86_400);
+        let days = value_day - reference_day;
+        // Calendar months and years between the two days
+        let calendar = || {
+            let (value_year, value_month, _) = convert::civil_from_days(value_day)?;
+            let (reference_year, reference_month, _) = convert::civil_from_days(reference_day)?;
+            let years = i64::from(value_year) - i64::from(reference_year);
+            Some((
+                years * 12 + i64::from(value_month) - i64::from(reference_month),
+                years,
+            ))
+        };
+        let rounding = self.rounding;
+        let unit = match self.unit {
+            Some(unit) => unit,
+            None if delta.abs() < 60 => RelativeUnit::Second,
+            None if rounding.divide(delta, 60).abs() < 60 => RelativeUnit::Minute,
+            None if rounding.divide(delta, 3600).abs() < 24 => RelativeUnit::Hour,
+            None if days.abs() < 7 => RelativeUnit::Day,
+            None => match calendar()? {
+                (months, _) if days.abs() < 28 || months == 0 => RelativeUnit::Week,
+                (months, _) if months.abs() < 12 => RelativeUnit::Month,
+                _ => RelativeUnit::Year,
+            },
+        };
+        let count = match unit {
+            RelativeUnit::Second => delta,
+            RelativeUnit::Minute => rounding.divide(delta, 60),
+            RelativeUnit::Hour => rounding.divide(delta, 3600),
+            RelativeUnit::Day => days,
+            RelativeUnit::Week => rounding.divide(days, 7),
+            RelativeUnit::Month => calendar()?.0,
+            RelativeUnit::Year => calendar()?.1,
+        };
+        Some((unit, count))
+    }
+}
+
+fn now() -> i64 {
+    #[cfg(feature = "test-util")]
+    if let Some(now) = crate::test_util::frozen_now() {
+        return now;
+    }
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => i64::try_from(d.as_secs()).unwrap_or(i64::MAX),
+        Err(e) => i64::try_from(e.duration().as_secs()).map_or(i64::MIN, |secs| -secs),
+    }
+}
+
+/// The unit a relative phrase counts in, see [`Relative::with_unit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeUnit {
+    /// "in 30 seconds"
+    Second,
+    /// "5 minutes ago"
+    Minute,
+    /// "in 2 hours"
+    Hour,
+    /// "yesterday", "in 3 days"
+    Day,
+    /// "last week", "in 2 weeks"
+    Week,
+    /// "next month"
+    Month,
+    /// "2 years ago"
+    Year,
+}
+
+impl RelativeUnit {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "second" => Self::Second,
+            "minute" => Self::Minute,
+            "hour" => Self::Hour,
+            "day" => Self::Day,
+            "week" => Self::Week,
+            "month" => Self::Month,
+            "year" => Self::Year,
+            _ => return None,
+        })
+    }
+}
+
+/// Whether relative phrases may use words instead of numbers
+///
+/// This is the `numeric` option of `Intl.RelativeTimeFormat`, but words
+/// are the default here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RelativeNumeric {
+    /// Always a number, like "1 day ago" or "in 0 days"
+    Always,
+    /// Words where the locale has them, like "yesterday" or "today"
+    #[default]
+    Auto,
+}
+
+impl RelativeNumeric {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "always" => Self::Always,
+            "auto" => Self::Auto,
+            _ => return None,
+        })
+    }
+}
+
+/// How relative counts are rounded, see [`Relative::with_rounding`]
+///
+/// This applies to the distance, whether in the past or the future, in
+/// seconds, minutes, hours and weeks.  Days, months and years are whole
+/// calendar units between the two dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RelativeRounding {
+    /// Down, "59 minutes ago" until a full hour has passed
+    #[default]
+    Floor,
+    /// To the nearest, "1 hour ago" from 59 minutes and 30 seconds
+    Round,
+    /// Up, "1 hour ago" from 59 minutes and 1 second
+    Ceil,
+}
+
+impl RelativeRounding {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "floor" => Self::Floor,
+            "round" => Self::Round,
+            "ceil" => Self::Ceil,
+            _ => return None,
+        })
+    }
+
+    /// Divides `n` by a positive `d`, rounding away from zero or not
+    fn divide(self, n: i64, d: i64) -> i64 {
+        let remainder = (n % d).abs();
+        let away = match self {
+            Self::Floor => false,
+            Self::Round => 2 * remainder >= d,
+            Self::Ceil => remainder != 0,
+        };
+        n / d + if away { n.signum() } else { 0 }
+    }
+}
+
+/// The relative options of a DATETIME call
+///
+/// They only apply with `relative`, which can come after them.
+#[derive(Debug, Default)]
+pub(crate) struct RelativeArgs {
+    pub(crate) threshold: Option<Duration>,
+    pub(crate) unit: Option<RelativeUnit>,
+    pub(crate) numeric: Option<RelativeNumeric>,
+    pub(crate) rounding: Option<RelativeRounding>,
+    pub(crate) now_cutoff: Option<Duration>,
+}
+
+impl RelativeArgs {
+    pub(crate) fn apply(self, relative: &mut Relative) {
+        if let Some(threshold) = self.threshold {
+            relative.threshold = threshold;
+        }
+        if let Some(unit) = self.unit {
+            relative.unit = Some(unit);
+        }
+        if let Some(numeric) = self.numeric {
+            relative.numeric = numeric;
+        }
+        if let Some(rounding) = self.rounding {
+            relative.rounding = rounding;
+        }
+        if let Some(now_cutoff) = self.now_cutoff {
+            relative.now_cutoff = now_cutoff;
+        }
+    }
+}
+
+/// What building [`RelativeFormatters`] reads from [`Relative`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct FormattersKey {
+    numeric: RelativeNumeric,
+    // Whether "now" needs its own formatter
+    now: bool,
+}
+
+impl Relative {
+    pub(crate) fn formatters_key(&self) -> FormattersKey {
+        FormattersKey {
+            numeric: self.numeric,
+            now: self.numeric == RelativeNumeric::Always && !self.now_cutoff.is_zero(),
+        }
+    }
+}
+
+/// Relative time formatters for a locale, one per unit
+pub(crate) struct RelativeFormatters {
+    formatters: [Option<RelativeTimeFormatter>; 7],
+    // For "now" when the others always write numbers
+    now: Option<RelativeTimeFormatter>,
+}
+
+impl RelativeFormatters {
+    /// Loads the formatters, leaving out units without locale data
+    pub(crate) fn new(provider: Provider, locale: &DataLocale, key: FormattersKey) -> Self {
+        let units = [
+            RelativeUnit::Second,
+            RelativeUnit::Minute,
+            RelativeUnit::Hour,
+            RelativeUnit::Day,
+            RelativeUnit::Week,
+            RelativeUnit::Month,
+            RelativeUnit::Year,
+        ];
+        let numeric = key.numeric;
+        let now = key
+            .now
+            .then(|| {
+                load_unit(
+                    provider,
+                    locale,
+                    RelativeUnit::Second,
+                    RelativeNumeric::Auto,
+                )
+            })
+            .flatten();
+        Self {
+            formatters: units.map(|unit| load_unit(provider, locale, unit, numeric)),
+            now,
+        }
+    }
+
+    /// Writes a relative phrase for `value`
+    ///
+    /// Returns `Ok(false)` without writing anything when `value` is too
+    /// far from the reference time or locale data is missing.
+    pub(crate) fn write_to<W: fmt::Write + ?Sized>(
+        &self,
+        relative: &Relative,
+        value: i64,
+        offset: i64,
+        sink: &mut W,
+    ) -> Result<bool, fmt::Error> {
+        let Some((unit, count)) = relative.select(value, offset) else {
+            return Ok(false);
+        };
+        let formatter = match (&self.now, unit, count) {
+            (Some(now), RelativeUnit::Second, 0) => Some(now),
+            _ => self.formatters[unit as usize].as_ref(),
+        };
+        let Some(formatter) = formatter else {
+            return Ok(false);
+        };
+        formatter.format(FixedDecimal::from(count)).write_to(sink)?;
+        Ok(true)
+    }
+}
+
+fn load_unit(
+    provider: Provider,
+    locale: &DataLocale,
+    unit: RelativeUnit,
+    numeric: RelativeNumeric,
+) -> Option<RelativeTimeFormatter> {
+    let options = RelativeTimeFormatterOptions {
+        numeric: match numeric {
+            RelativeNumeric::Always => Numeric::Always,
+            RelativeNumeric::Auto => Numeric::Auto,
+        },
+    };
+    macro_rules! load_unit {
+        ($compiled:ident, $any:ident, $buffer:ident) => {
+            load!(
+                provider,
+                RelativeTimeFormatter::$compiled,
+                RelativeTimeFormatter::$any,
+                RelativeTimeFormatter::$buffer,
+                locale,
+                options,
+            )
+        };
+    }
+    match unit {
+        RelativeUnit::Second => load_unit!(
+            try_new_long_second,
+            try_new_long_second_with_any_provider,
+            try_new_long_second_with_buffer_provider
+        ),
+        RelativeUnit::Minute => load_unit!(
+            try_new_long_minute,
+            try_new_long_minute_with_any_provider,
+            try_new_long_minute_with_buffer_provider
+        ),
+        RelativeUnit::Hour => load_unit!(
+            try_new_long_hour,
+            try_new_long_hour_with_any_provider,
+            try_new_long_hour_with_buffer_provider
+        ),
+        RelativeUnit::Day => load_unit!(
+            try_new_long_day,
+            try_new_long_day_with_any_provider,
+            try_new_long_day_with_buffer_provider
+        ),
+        RelativeUnit::Week => load_unit!(
+            try_new_long_week,
+            try_new_long_week_with_any_provider,
+            try_new_long_week_with_buffer_provider
+        ),
+        RelativeUnit::Month => load_unit!(
+            try_new_long_month,
+            try_new_long_month_with_any_provider,
+            try_new_long_month_with_buffer_provider
+        ),
+        RelativeUnit::Year => load_unit!(
+            try_new_long_year,
+            try_new_long_year_with_any_provider,
+            try_new_long_year_with_buffer_provider
+        ),
+    }
+    .ok()
+}
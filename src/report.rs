@@ -0,0 +1,86 @@
+//! Collects datetime failures while a pattern is being formatted
+//!
+//! Neither Fluent functions nor [`FluentType`](fluent_bundle::types::FluentType)
+//! implementations get access to the bundle's error vector.  Formatting
+//! a pattern happens synchronously on the calling thread though, so
+//! failures are recorded in a thread-local while
+//! [`BundleExt::format_pattern_reporting`](crate::BundleExt::format_pattern_reporting)
+//! runs, and turned into [`FluentError`]s afterwards.
+
+use std::cell::{Cell, RefCell};
+
+use fluent_bundle::resolver::ResolverError;
+use fluent_bundle::FluentError;
+
+thread_local! {
+    static REPORTED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+    /// Address of the pattern being formatted, or 0
+    static PATTERN: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Records a failure, if failures are being collected on this thread,
+/// and logs it with the `tracing` feature
+///
+/// The description is only built when needed.
+pub(crate) fn report(describe: impl FnOnce() -> String) {
+    let collecting = REPORTED.with(|reported| reported.borrow().is_some());
+    #[cfg(feature = "tracing")]
+    let logging = tracing::enabled!(tracing::Level::WARN);
+    #[cfg(not(feature = "tracing"))]
+    let logging = false;
+    if !collecting && !logging {
+        return;
+    }
+    let description = describe();
+    #[cfg(feature = "tracing")]
+    if logging {
+        tracing::warn!("{description}");
+    }
+    REPORTED.with(|reported| {
+        if let Some(reported) = reported.borrow_mut().as_mut() {
+            reported.push(description);
+        }
+    });
+}
+
+/// The pattern being formatted by
+/// [`BundleExt::format_pattern_reporting`](crate::BundleExt::format_pattern_reporting),
+/// identified by address, or 0
+pub(crate) fn current_pattern() -> usize {
+    PATTERN.get()
+}
+
+/// Runs `f`, formatting `pattern`, appending the failures it reports to
+/// `errors`
+pub(crate) fn collect<P, T>(
+    errors: &mut Vec<FluentError>,
+    pattern: &P,
+    f: impl FnOnce() -> T,
+) -> T {
+    struct Restore(Option<Vec<String>>, usize);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            REPORTED.with(|reported| *reported.borrow_mut() = self.0.take());
+            PATTERN.set(self.1);
+        }
+    }
+
+    let restore = Restore(
+        REPORTED.with(|reported| reported.replace(Some(Vec::new()))),
+        PATTERN.replace(pattern as *const P as usize),
+    );
+    let result = f();
+    let collected = REPORTED
+        .with(|reported| reported.take())
+        .unwrap_or_default();
+    drop(restore);
+    // fluent_bundle's error types are closed; NoValue is the variant
+    // that can carry a description
+    errors.extend(
+        collected
+            .into_iter()
+            .map(|description| FluentError::ResolverError(ResolverError::NoValue(description))),
+    );
+    result
+}
@@ -0,0 +1,177 @@
+//! Finding the DATETIME calls of Fluent resources
+
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use fluent_syntax::ast;
+
+use crate::{supported_options, FluentDateTimeError, FluentDateTimeOptions, TimeZone};
+
+/// The message or term a call is in
+#[derive(Clone, Copy)]
+pub(crate) struct Location<'s> {
+    /// The message or term id
+    pub(crate) entry: &'s str,
+    pub(crate) is_term: bool,
+    pub(crate) attribute: Option<&'s str>,
+}
+
+/// A DATETIME call found in a resource
+pub(crate) struct DateTimeCall<'s> {
+    pub(crate) location: Location<'s>,
+    /// Whether the datetime argument is missing or a literal
+    pub(crate) no_datetime: bool,
+    /// Options given as literals
+    args: FluentArgs<'s>,
+    /// The time zone positional argument, if a literal
+    time_zone: Option<&'s str>,
+    /// Whether the time zone positional argument is given at runtime, as
+    /// in `DATETIME($date, $tz)`
+    pub(crate) dynamic_time_zone: bool,
+}
+
+impl DateTimeCall<'_> {
+    /// Applies the literal options of the call to `options`, like
+    /// [`DATETIME`](crate::DATETIME) does
+    pub(crate) fn apply(
+        &self,
+        mut options: FluentDateTimeOptions,
+    ) -> Result<FluentDateTimeOptions, FluentDateTimeError> {
+        if let Some(tz) = self.time_zone {
+            options.time_zone = Some(
+                TimeZone::from_argument(tz)
+                    .ok_or_else(|| FluentDateTimeError::UnknownTimeZone(tz.to_string()))?,
+            );
+        }
+        options.merge_args(&self.args)?;
+        Ok(options)
+    }
+
+    /// The named options of the call that DATETIME doesn't know
+    pub(crate) fn unknown_options(&self) -> impl Iterator<Item = &str> {
+        self.args
+            .iter()
+            .map(|(name, _)| name)
+            .filter(|name| !supported_options().iter().any(|o| o.name == *name))
+    }
+}
+
+/// The DATETIME calls of some resources, in messages, terms and attributes
+pub(crate) fn datetime_calls<'s>(
+    resources: impl IntoIterator<Item = &'s FluentResource>,
+) -> Vec<DateTimeCall<'s>> {
+    let mut calls = vec![];
+    for resource in resources {
+        for entry in resource.entries() {
+            let (id, is_term, value, attributes) = match entry {
+                ast::Entry::Message(m) => (m.id.name, false, m.value.as_ref(), &m.attributes),
+                ast::Entry::Term(t) => (t.id.name, true, Some(&t.value), &t.attributes),
+                _ => continue,
+            };
+            let location = Location {
+                entry: id,
+                is_term,
+                attribute: None,
+            };
+            if let Some(pattern) = value {
+                scan_pattern(pattern, location, &mut calls);
+            }
+            for attribute in attributes {
+                let location = Location {
+                    attribute: Some(attribute.id.name),
+                    ..location
+                };
+                scan_pattern(&attribute.value, location, &mut calls);
+            }
+        }
+    }
+    calls
+}
+
+fn scan_pattern<'s>(
+    pattern: &ast::Pattern<&'s str>,
+    location: Location<'s>,
+    calls: &mut Vec<DateTimeCall<'s>>,
+) {
+    for element in &pattern.elements {
+        if let ast::PatternElement::Placeable { expression } = element {
+            scan_expression(expression, location, calls);
+        }
+    }
+}
+
+fn scan_expression<'s>(
+    expression: &ast::Expression<&'s str>,
+    location: Location<'s>,
+    calls: &mut Vec<DateTimeCall<'s>>,
+) {
+    match expression {
+        ast::Expression::Select { selector, variants } => {
+            scan_inline(selector, location, calls);
+            for variant in variants {
+                scan_pattern(&variant.value, location, calls);
+            }
+        }
+        ast::Expression::Inline(inline) => scan_inline(inline, location, calls),
+    }
+}
+
+fn scan_inline<'s>(
+    inline: &ast::InlineExpression<&'s str>,
+    location: Location<'s>,
+    calls: &mut Vec<DateTimeCall<'s>>,
+) {
+    match inline {
+        ast::InlineExpression::FunctionReference { id, arguments } if id.name == "DATETIME" => {
+            let mut call = DateTimeCall {
+                location,
+                no_datetime: matches!(
+                    arguments.positional.first(),
+                    None | Some(
+                        ast::InlineExpression::StringLiteral { .. }
+                            | ast::InlineExpression::NumberLiteral { .. }
+                    )
+                ),
+                args: FluentArgs::new(),
+                time_zone: None,
+                dynamic_time_zone: false,
+            };
+            for arg in &arguments.named {
+                let value = match &arg.value {
+                    ast::InlineExpression::StringLiteral { value } => FluentValue::from(*value),
+                    ast::InlineExpression::NumberLiteral { value } => {
+                        FluentValue::try_number(value)
+                    }
+                    // Fluent only parses literals as named arguments
+                    _ => continue,
+                };
+                call.args.set(arg.name.name, value);
+            }
+            match arguments.positional.get(1) {
+                Some(ast::InlineExpression::StringLiteral { value }) => {
+                    call.time_zone = Some(value);
+                }
+                Some(_) => call.dynamic_time_zone = true,
+                None => (),
+            }
+            calls.push(call);
+            for arg in &arguments.positional {
+                scan_inline(arg, location, calls);
+            }
+        }
+        ast::InlineExpression::FunctionReference { arguments, .. }
+        | ast::InlineExpression::TermReference {
+            arguments: Some(arguments),
+            ..
+        } => {
+            for arg in &arguments.positional {
+                scan_inline(arg, location, calls);
+            }
+            for arg in &arguments.named {
+                scan_inline(&arg.value, location, calls);
+            }
+        }
+        ast::InlineExpression::Placeable { expression } => {
+            scan_expression(expression, location, calls)
+        }
+        _ => (),
+    }
+}
@@ -0,0 +1,29 @@
+//! Whether datetimes are instants or wall-clock times
+
+/// Whether a [`FluentDateTime`](crate::FluentDateTime) is a point in
+/// time or a wall-clock time
+///
+/// ```
+/// use fluent_datetime::{datetime, FluentDateTimeError, Semantics, TimeZone};
+///
+/// // An alarm rings at 9:00 wherever its owner is
+/// let alarm = datetime!(1989-11-09 09:00).with_semantics(Semantics::Floating);
+/// assert!(alarm.clone().with_utc_offset(3600).is_none());
+///
+/// let alarm = alarm.map_options(|o| o.set_time_zone(TimeZone::from_iana("Etc/GMT-2")));
+/// assert_eq!(alarm.format(&"en-US".parse()?), Err(FluentDateTimeError::FloatingTimeZone));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Semantics {
+    /// A point in time; given a time zone, the datetime is taken to be in
+    /// UTC and is displayed as the wall-clock time of that zone
+    #[default]
+    Instant,
+    /// A wall-clock time, displayed as is wherever the reader is; time
+    /// zones are refused, and those of
+    /// [`DateTimeSupport::with_defaults`](crate::DateTimeSupport::with_defaults)
+    /// ignored
+    Floating,
+}
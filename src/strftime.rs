@@ -0,0 +1,236 @@
+//! Moving from strftime format strings to localized options
+
+use icu_datetime::options::length;
+
+use crate::{FluentDateTimeError, FluentDateTimeOptions, WeekNumber};
+
+/// The DATETIME options closest to a strftime format string
+///
+/// Codebases often hard-code formats like `"%Y-%m-%d %H:%M"`, as in C,
+/// Python or [chrono].  This picks the date and time styles that show
+/// the same fields, so such formats can be replaced with [`DATETIME`]
+/// calls that follow each locale's conventions.  The output then changes:
+/// fields are ordered, separated and padded as the locale does, and the
+/// hour cycle is the locale's.
+///
+/// Directives map to the shortest style that has their field: numbers
+/// to `short`, abbreviated names and seconds to `medium`, full month
+/// names and time zones to `long`, and full weekday names to `full`.
+/// Week numbers map to [`WeekNumber`], which replaces the date.
+/// Directives without a localized counterpart, like `%s` or `%j`, are
+/// listed by [`unmapped`](Self::unmapped), and unknown ones are an
+/// error.
+///
+/// ```
+/// use fluent_datetime::{datetime, StrftimeConversion};
+///
+/// let conversion = StrftimeConversion::parse("%Y-%m-%d %H:%M")?;
+/// assert_eq!(conversion.to_fluent_args(), r#"dateStyle: "short", timeStyle: "short""#);
+/// let datetime = datetime!(1989-11-09 23:30).map_options(|o| *o = conversion.options().clone());
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "11/9/89, 11:30\u{202f}PM");
+/// assert_eq!(datetime.format(&"de-DE".parse()?).unwrap(), "09.11.89, 23:30");
+///
+/// let conversion = StrftimeConversion::parse("%A, %B %-d, %Y")?;
+/// assert_eq!(conversion.to_fluent_args(), r#"dateStyle: "full""#);
+/// let conversion = StrftimeConversion::parse("%G-W%V-%u")?;
+/// assert_eq!(conversion.to_fluent_args(), r#"weekNumber: "isoWeekDate""#);
+/// let conversion = StrftimeConversion::parse("%d/%m/%Y %H:%M:%S.%3f")?;
+/// assert_eq!(conversion.to_fluent_args(), r#"dateStyle: "short", timeStyle: "medium""#);
+/// assert_eq!(conversion.unmapped(), ["%3f"]);
+/// assert!(StrftimeConversion::parse("%Q").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [chrono]: https://docs.rs/chrono/latest/chrono/format/strftime/
+/// [`DATETIME`]: crate::DATETIME
+#[derive(Debug, Clone)]
+pub struct StrftimeConversion {
+    options: FluentDateTimeOptions,
+    unmapped: Vec<String>,
+}
+
+impl StrftimeConversion {
+    /// Converts a format string
+    ///
+    /// Fails with [`FluentDateTimeError::UnknownDirective`] on directives
+    /// that aren't strftime's or chrono's.
+    pub fn parse(format: &str) -> Result<Self, FluentDateTimeError> {
+        let mut date = None;
+        let mut time = None;
+        let mut week = None;
+        let mut has_year = false;
+        let mut has_iso_weekday = false;
+        let mut date_directives = vec![];
+        let mut unmapped = vec![];
+        let mut chars = format.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+            let mut directive = String::from('%');
+            // Padding and case flags, then chrono's widths, as in `%.3f`
+            // and `%:z`
+            while let Some(&c) = chars.peek() {
+                if !matches!(c, '-' | '_' | '0'..='9' | '^' | '#' | '.' | ':') {
+                    break;
+                }
+                directive.push(c);
+                chars.next();
+            }
+            let Some(conversion) = chars.next() else {
+                return Err(FluentDateTimeError::UnknownDirective(directive));
+            };
+            directive.push(conversion);
+            let mut date_level = |level| {
+                date = date.max(Some(level));
+                date_directives.push(directive.clone());
+            };
+            match conversion {
+                'Y' | 'y' => {
+                    has_year = true;
+                    date_level(Level::Short);
+                }
+                'm' | 'd' | 'e' | 'D' | 'F' | 'x' => date_level(Level::Short),
+                'b' | 'h' | 'a' => date_level(Level::Medium),
+                'B' => date_level(Level::Long),
+                'A' => date_level(Level::Full),
+                'c' => {
+                    date_level(Level::Medium);
+                    time = time.max(Some(Level::Medium));
+                }
+                '+' => {
+                    date_level(Level::Short);
+                    time = time.max(Some(Level::Long));
+                }
+                'H' | 'k' | 'I' | 'l' | 'M' | 'R' | 'p' | 'P' => {
+                    time = time.max(Some(Level::Short));
+                }
+                'S' | 'T' | 'X' | 'r' => time = time.max(Some(Level::Medium)),
+                'Z' | 'z' => time = time.max(Some(Level::Long)),
+                'U' | 'W' => week = week.or(Some(false)),
+                'V' => week = Some(true),
+                'G' | 'g' => has_year = true,
+                'u' => has_iso_weekday = true,
+                'C' | 'j' | 'w' | 's' | 'f' => unmapped.push(directive),
+                '%' | 'n' | 't' => (),
+                _ => return Err(FluentDateTimeError::UnknownDirective(directive)),
+            }
+        }
+
+        let mut options = FluentDateTimeOptions::default();
+        match week {
+            Some(iso) => {
+                options.set_week_number(Some(match (iso, has_year) {
+                    (true, true) if has_iso_weekday => WeekNumber::IsoWeekDate,
+                    (_, true) => WeekNumber::WithYear,
+                    (_, false) => WeekNumber::Numeric,
+                }));
+                // Week numbers replace the date
+                unmapped.extend(
+                    date_directives
+                        .into_iter()
+                        .filter(|d| !d.ends_with(['Y', 'y'])),
+                );
+            }
+            None => {
+                if has_iso_weekday {
+                    unmapped.push("%u".to_string());
+                }
+                options.set_date_style(date.map(Level::date));
+            }
+        }
+        options.set_time_style(time.map(Level::time));
+        Ok(Self { options, unmapped })
+    }
+
+    /// The options, to attach to datetimes with
+    /// [`FluentDateTime::map_options`](crate::FluentDateTime::map_options)
+    pub fn options(&self) -> &FluentDateTimeOptions {
+        &self.options
+    }
+
+    /// The directives left out, in the order of the format string
+    pub fn unmapped(&self) -> &[String] {
+        &self.unmapped
+    }
+
+    /// The options as DATETIME arguments, like
+    /// `dateStyle: "short", timeStyle: "short"`, for rewriting messages
+    pub fn to_fluent_args(&self) -> String {
+        let mut args = vec![];
+        if let Some(style) = self.options.date_style {
+            args.push(("dateStyle", Level::from_date(style).keyword()));
+        }
+        if let Some(style) = self.options.time_style {
+            args.push(("timeStyle", Level::from_time(style).keyword()));
+        }
+        if let Some(week_number) = self.options.week_number {
+            let keyword = match week_number {
+                WeekNumber::Numeric => "numeric",
+                WeekNumber::WithYear => "withYear",
+                WeekNumber::IsoWeekDate => "isoWeekDate",
+            };
+            args.push(("weekNumber", keyword));
+        }
+        args.iter()
+            .map(|(name, value)| format!("{name}: \"{value}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// A date or time style, from the most compact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Short,
+    Medium,
+    Long,
+    Full,
+}
+
+impl Level {
+    fn date(self) -> length::Date {
+        match self {
+            Self::Short => length::Date::Short,
+            Self::Medium => length::Date::Medium,
+            Self::Long => length::Date::Long,
+            Self::Full => length::Date::Full,
+        }
+    }
+
+    fn time(self) -> length::Time {
+        match self {
+            Self::Short => length::Time::Short,
+            Self::Medium => length::Time::Medium,
+            Self::Long => length::Time::Long,
+            Self::Full => length::Time::Full,
+        }
+    }
+
+    fn from_date(style: length::Date) -> Self {
+        match style {
+            length::Date::Full => Self::Full,
+            length::Date::Long => Self::Long,
+            length::Date::Medium => Self::Medium,
+            _ => Self::Short,
+        }
+    }
+
+    fn from_time(style: length::Time) -> Self {
+        match style {
+            length::Time::Full => Self::Full,
+            length::Time::Long => Self::Long,
+            length::Time::Medium => Self::Medium,
+            _ => Self::Short,
+        }
+    }
+
+    fn keyword(self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Medium => "medium",
+            Self::Long => "long",
+            Self::Full => "full",
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! A description of the options DATETIME understands, for tooling
+
+/// A named option of [`DATETIME`](crate::DATETIME), see [`supported_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OptionInfo {
+    /// The option name, as in Fluent, for example `dateStyle`
+    pub name: &'static str,
+    /// The values the option takes
+    pub values: OptionValues,
+    /// The crate version that added the option
+    pub since: &'static str,
+}
+
+/// The values an option takes, see [`OptionInfo`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OptionValues {
+    /// One of these strings
+    OneOf(&'static [&'static str]),
+    /// An IANA time zone identifier, like `Europe/Berlin`
+    TimeZone,
+    /// A Unicode extension keyword value, like `latn` or `japanese`
+    Keyword,
+    /// A number of seconds
+    Seconds,
+    /// A UTC offset, like `+02:00`, or a number of minutes east of UTC
+    UtcOffset,
+}
+
+const OPTIONS: &[OptionInfo] = &[
+    OptionInfo {
+        name: "dateStyle",
+        values: OptionValues::OneOf(&["full", "long", "medium", "short"]),
+        since: "0.1.0",
+    },
+    OptionInfo {
+        name: "timeStyle",
+        values: OptionValues::OneOf(&["full", "long", "medium", "short"]),
+        since: "0.1.0",
+    },
+    OptionInfo {
+        name: "timeZone",
+        values: OptionValues::TimeZone,
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "utcOffset",
+        values: OptionValues::UtcOffset,
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "casing",
+        values: OptionValues::OneOf(&["middle-of-sentence", "beginning-of-sentence", "standalone"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "numberingSystem",
+        values: OptionValues::Keyword,
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "calendar",
+        values: OptionValues::Keyword,
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "hourCycle",
+        values: OptionValues::OneOf(&["h11", "h12", "h23", "h24"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "alignment",
+        values: OptionValues::OneOf(&["natural", "column"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "weekNumber",
+        values: OptionValues::OneOf(&["numeric", "withYear", "isoWeekDate"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "era",
+        values: OptionValues::OneOf(&["long", "short", "narrow"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "relative",
+        values: OptionValues::OneOf(&["auto"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "relativeThreshold",
+        values: OptionValues::Seconds,
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "relativeUnit",
+        values: OptionValues::OneOf(&["second", "minute", "hour", "day", "week", "month", "year"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "relativeNumeric",
+        values: OptionValues::OneOf(&["always", "auto"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "relativeRounding",
+        values: OptionValues::OneOf(&["floor", "round", "ceil"]),
+        since: "0.2.0",
+    },
+    OptionInfo {
+        name: "relativeNowCutoff",
+        values: OptionValues::Seconds,
+        since: "0.2.0",
+    },
+];
+
+/// The named options [`DATETIME`](crate::DATETIME) understands
+///
+/// Translation editors can use this to complete and check options
+/// without keeping their own list.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::FluentArgs;
+/// use fluent_datetime::{supported_options, FluentDateTimeOptions, OptionValues};
+///
+/// let date_style = supported_options().iter().find(|o| o.name == "dateStyle").unwrap();
+/// assert_eq!(date_style.values, OptionValues::OneOf(&["full", "long", "medium", "short"]));
+/// assert_eq!(date_style.since, "0.1.0");
+/// let version = env!("CARGO_PKG_VERSION");
+/// assert!(supported_options().iter().all(|o| ["0.1.0", version].contains(&o.since)));
+///
+/// for option in supported_options() {
+///     if let OptionValues::OneOf(values) = option.values {
+///         for &value in values {
+///             let mut args = FluentArgs::new();
+///             args.set(option.name, value);
+///             assert!(FluentDateTimeOptions::from_args(&args).is_ok());
+///         }
+///     }
+/// }
+/// ```
+pub fn supported_options() -> &'static [OptionInfo] {
+    OPTIONS
+}
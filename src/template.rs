@@ -0,0 +1,234 @@
+//! Datetime formatting as a template filter
+//!
+//! [`DateTimeFilter`] takes the options of [`DATETIME`](crate::DATETIME),
+//! under the same names, and builds formatters in a [`FormatterCache`]
+//! that can be shared with bundles, see
+//! [`DateTimeSupport::with_cache`](crate::DateTimeSupport::with_cache).
+//! It registers as a `datetime` filter with minijinja and tera, behind the
+//! features of the same names.  Askama filters are plain functions, which
+//! can call [`DateTimeFilter::format`]:
+//!
+//! ```ignore
+//! mod filters {
+//!     static FILTER: LazyLock<DateTimeFilter> = LazyLock::new(DateTimeFilter::new);
+//!
+//!     pub fn datetime(date: &str, locale: &str) -> askama::Result<String> {
+//!         let locale = locale.parse().map_err(|e| askama::Error::Custom(Box::new(e)))?;
+//!         FILTER
+//!             .format_str(date, &locale, &FluentArgs::new())
+//!             .map_err(|e| askama::Error::Custom(Box::new(e)))
+//!     }
+//! }
+//! ```
+
+use fluent_bundle::FluentArgs;
+
+use crate::{FluentDateTime, FluentDateTimeError, FormatterCache};
+
+/// Formats datetimes for template engines, with the options model and
+/// formatter cache of the Fluent function
+///
+/// Template values are ISO 8601 strings, see
+/// [`FromStr`](std::str::FromStr) for [`FluentDateTime`], or Unix
+/// timestamps in seconds.  The locale is given by the `locale` argument,
+/// or else by [`with_locale`](Self::with_locale).
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::FluentArgs;
+/// use fluent_datetime::DateTimeFilter;
+///
+/// let filter = DateTimeFilter::new();
+/// let mut args = FluentArgs::new();
+/// args.set("dateStyle", "long");
+/// assert_eq!(
+///     filter.format_str("1989-11-09T23:30:00", &"en-US".parse()?, &args)?,
+///     "November 9, 1989"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateTimeFilter {
+    cache: FormatterCache,
+    locale: Option<unic_langid::LanguageIdentifier>,
+}
+
+impl Default for DateTimeFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateTimeFilter {
+    /// A filter with a cache of its own
+    pub fn new() -> Self {
+        Self {
+            cache: FormatterCache::with_capacity(64),
+            locale: None,
+        }
+    }
+
+    /// Builds formatters in `cache`, typically the one given to
+    /// [`DateTimeSupport::with_cache`](crate::DateTimeSupport::with_cache)
+    pub fn with_cache(mut self, cache: FormatterCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// The locale when the template doesn't pass one
+    pub fn with_locale(mut self, locale: unic_langid::LanguageIdentifier) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Formats `value` with its options, overridden by `args`
+    ///
+    /// Falls back to ISO 8601 if formatting fails, including when no
+    /// formatter can be built, like Fluent rendering does; invalid
+    /// arguments are errors.
+    pub fn format(
+        &self,
+        value: &FluentDateTime,
+        locale: &unic_langid::LanguageIdentifier,
+        args: &FluentArgs,
+    ) -> Result<String, FluentDateTimeError> {
+        let mut options = value.options.clone();
+        options.merge_args(args)?;
+        let options = options.for_value(value);
+        let Ok(formatter) = self.cache.get(locale, &options) else {
+            return Ok(value.to_iso8601());
+        };
+        let mut s = String::new();
+        if value.write_with(&formatter, &options, &mut s).is_err() {
+            s = value.to_iso8601();
+        }
+        Ok(s)
+    }
+
+    /// Formats an ISO 8601 string, as [`format`](Self::format)
+    pub fn format_str(
+        &self,
+        value: &str,
+        locale: &unic_langid::LanguageIdentifier,
+        args: &FluentArgs,
+    ) -> Result<String, FluentDateTimeError> {
+        self.format(&value.parse()?, locale, args)
+    }
+
+    /// The locale of a `locale` argument, or the default one
+    #[cfg(any(feature = "minijinja", feature = "tera"))]
+    fn locale(&self, arg: Option<&str>) -> Result<unic_langid::LanguageIdentifier, String> {
+        match (arg, &self.locale) {
+            (Some(arg), _) => arg.parse().map_err(|_| format!("invalid locale {arg}")),
+            (None, Some(locale)) => Ok(locale.clone()),
+            (None, None) => Err("missing locale argument".to_string()),
+        }
+    }
+
+    /// Registers the filter as `datetime`
+    ///
+    /// ```
+    /// use fluent_datetime::DateTimeFilter;
+    ///
+    /// let mut env = minijinja::Environment::new();
+    /// DateTimeFilter::new().register_minijinja(&mut env);
+    /// let out = env.render_str(
+    ///     r#"{{ "1989-11-09" | datetime(locale="de-DE", dateStyle="medium") }}"#,
+    ///     (),
+    /// )?;
+    /// assert_eq!(out, "09.11.1989");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "minijinja")]
+    pub fn register_minijinja(&self, env: &mut minijinja::Environment) {
+        use fluent_bundle::FluentValue;
+        use minijinja::value::{Kwargs, Value, ValueKind};
+        use minijinja::{Error, ErrorKind};
+
+        let filter = self.clone();
+        env.add_filter(
+            "datetime",
+            move |value: Value, kwargs: Kwargs| -> Result<String, Error> {
+                let invalid = |e: String| Error::new(ErrorKind::InvalidOperation, e);
+                let value = match (value.as_str(), value.as_i64()) {
+                    (Some(s), _) => s
+                        .parse()
+                        .map_err(|e: FluentDateTimeError| invalid(e.to_string()))?,
+                    (None, Some(secs)) => from_timestamp(secs).map_err(invalid)?,
+                    _ => return Err(invalid(format!("not a datetime: {value}"))),
+                };
+                let locale = filter
+                    .locale(kwargs.get::<Option<&str>>("locale")?)
+                    .map_err(invalid)?;
+                let mut args = FluentArgs::new();
+                for name in kwargs.args().filter(|&name| name != "locale") {
+                    let arg: Value = kwargs.get(name)?;
+                    let arg = match arg.kind() {
+                        ValueKind::Number => FluentValue::from(f64::try_from(arg)?),
+                        _ => FluentValue::from(arg.to_string()),
+                    };
+                    args.set(name.to_string(), arg);
+                }
+                kwargs.assert_all_used()?;
+                filter
+                    .format(&value, &locale, &args)
+                    .map_err(|e| invalid(e.to_string()))
+            },
+        );
+    }
+
+    /// Registers the filter as `datetime`
+    ///
+    /// ```
+    /// use fluent_datetime::DateTimeFilter;
+    ///
+    /// let mut tera = tera::Tera::default();
+    /// DateTimeFilter::new().register_tera(&mut tera);
+    /// let out = tera.render_str(
+    ///     r#"{{ "1989-11-09" | datetime(locale="de-DE", dateStyle="medium") }}"#,
+    ///     &tera::Context::new(),
+    /// )?;
+    /// assert_eq!(out, "09.11.1989");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "tera")]
+    pub fn register_tera(&self, tera: &mut tera::Tera) {
+        use fluent_bundle::FluentValue;
+        use tera::Value;
+
+        let filter = self.clone();
+        tera.register_filter(
+            "datetime",
+            move |value: &Value, kwargs: &std::collections::HashMap<String, Value>| {
+                let value = match value {
+                    Value::String(s) => {
+                        s.parse().map_err(|e: FluentDateTimeError| e.to_string())?
+                    }
+                    Value::Number(n) => match n.as_i64() {
+                        Some(secs) => from_timestamp(secs)?,
+                        None => return Err(format!("not a datetime: {n}").into()),
+                    },
+                    value => return Err(format!("not a datetime: {value}").into()),
+                };
+                let locale = filter.locale(kwargs.get("locale").and_then(Value::as_str))?;
+                let mut args = FluentArgs::new();
+                for (name, arg) in kwargs.iter().filter(|(name, _)| *name != "locale") {
+                    let arg = match arg {
+                        Value::String(s) => FluentValue::from(s.clone()),
+                        Value::Number(n) => FluentValue::from(n.as_f64().unwrap_or(f64::NAN)),
+                        arg => FluentValue::from(arg.to_string()),
+                    };
+                    args.set(name.clone(), arg);
+                }
+                let formatted = filter
+                    .format(&value, &locale, &args)
+                    .map_err(|e| e.to_string())?;
+                Ok(Value::String(formatted))
+            },
+        );
+    }
+}
+
+#[cfg(any(feature = "minijinja", feature = "tera"))]
+fn from_timestamp(secs: i64) -> Result<FluentDateTime, String> {
+    crate::convert::from_unix(secs, 0).ok_or_else(|| format!("timestamp out of range: {secs}"))
+}
@@ -0,0 +1,92 @@
+//! Helpers for testing localized datetimes
+//!
+//! Enabled by the `test-util` cargo feature.
+
+use std::cell::Cell;
+
+use crate::{convert, FluentDateTime};
+
+thread_local! {
+    /// Unix seconds standing in for the current time
+    static FROZEN: Cell<Option<i64>> = const { Cell::new(None) };
+}
+
+/// Stops the clock for this thread, until the guard is dropped
+///
+/// Relative phrases (see [`Relative`](crate::Relative)) are then computed
+/// from `now` rather than the system time, so their output is stable.
+///
+/// ```
+/// use fluent_datetime::test_util::freeze_clock;
+/// use fluent_datetime::{datetime, Relative};
+///
+/// let _clock = freeze_clock(&datetime!(1989-11-10 09:00));
+/// let datetime = datetime!(1989-11-09 23:30)
+///     .map_options(|o| o.set_relative(Some(Relative::auto())));
+/// assert_eq!(datetime.format(&"en-US".parse()?).unwrap(), "9 hours ago");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use = "the clock runs again when the guard is dropped"]
+pub fn freeze_clock(now: &FluentDateTime) -> ClockGuard {
+    let previous = FROZEN.replace(Some(convert::to_unix(now.value())));
+    ClockGuard { previous }
+}
+
+/// Restarts the clock when dropped, see [`freeze_clock`]
+#[derive(Debug)]
+pub struct ClockGuard {
+    previous: Option<i64>,
+}
+
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        FROZEN.set(self.previous);
+    }
+}
+
+/// The frozen time, in Unix seconds, if any
+pub(crate) fn frozen_now() -> Option<i64> {
+    FROZEN.get()
+}
+
+/// Removes the bidi isolation marks Fluent puts around placeables
+///
+/// These are U+2068 FIRST STRONG ISOLATE and U+2069 POP DIRECTIONAL
+/// ISOLATE.  Directional marks from locale data are kept.
+pub fn strip_isolation(formatted: &str) -> String {
+    formatted
+        .chars()
+        .filter(|&c| c != '\u{2068}' && c != '\u{2069}')
+        .collect()
+}
+
+/// Asserts that formatted output is as expected, ignoring bidi isolation
+/// marks
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::test_util::assert_formatted_eq;
+/// use fluent_datetime::{datetime, BundleExt};
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support()?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "today = Today is {DATETIME($date)}".to_string(),
+/// ).unwrap()).unwrap();
+///
+/// let mut errors = vec![];
+/// let formatted = bundle.format_pattern(
+///     bundle.get_message("today").unwrap().value().unwrap(),
+///     Some(&FluentArgs::from_iter([("date", datetime!(1989-11-09))])),
+///     &mut errors,
+/// );
+/// assert_formatted_eq(formatted, "Today is 11/9/89");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[track_caller]
+pub fn assert_formatted_eq(formatted: impl AsRef<str>, expected: &str) {
+    assert_eq!(
+        strip_isolation(formatted.as_ref()),
+        strip_isolation(expected)
+    );
+}
@@ -0,0 +1,367 @@
+//! Time zones for displaying datetimes
+
+use std::sync::Arc;
+
+use icu_calendar::{DateTime, Gregorian};
+use icu_timezone::{CustomTimeZone, GmtOffset, MetazoneCalculator, TimeZoneBcp47Id, ZoneVariant};
+
+use crate::provider;
+use crate::FluentDateTimeError;
+
+/// An IANA time zone, such as `Europe/Paris` or `UTC`
+///
+/// When a time zone is set in [`FluentDateTimeOptions`](crate::FluentDateTimeOptions),
+/// the datetime is taken to be in UTC and is displayed as the wall-clock
+/// time of that zone.  Time styles that show a zone name (`full` and `long`)
+/// display the name of this zone.
+///
+/// Without the `tzdb` cargo feature, only zones with a fixed offset
+/// (`UTC`, `Etc/GMT+5`…) can be converted to; formatting with other zones
+/// fails.  The feature bundles the IANA time zone database, for the
+/// offsets and daylight saving time of any zone, historical ones included.
+/// Plain UTC offsets, as in RFC 3339 datetimes, are zones too, see
+/// [`from_offset_seconds`](Self::from_offset_seconds).
+///
+/// ```
+/// use fluent_datetime::TimeZone;
+///
+/// let tz = TimeZone::from_iana("Asia/Calcutta").unwrap();
+/// assert_eq!(tz.iana_id(), "Asia/Kolkata");
+/// assert!(TimeZone::from_iana("Mars/Olympus_Mons").is_none());
+/// ```
+///
+/// In a Fluent message, the zone is usually passed as a second argument:
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+/// use fluent_datetime::{BundleExt, FluentDateTime};
+/// use icu_calendar::DateTime;
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.add_datetime_support()?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "now = {DATETIME($date, $tz, timeStyle: \"long\")}".to_string(),
+/// ).unwrap()).unwrap();
+///
+/// let datetime = DateTime::try_new_iso_datetime(1989, 11, 9, 23, 30, 0).unwrap();
+/// let mut errors = vec![];
+/// assert_eq!(
+///     bundle.format_pattern(
+///         bundle.get_message("now").unwrap().value().unwrap(),
+///         Some(&FluentArgs::from_iter([
+///             ("date", FluentValue::from(FluentDateTime::from(datetime))),
+///             ("tz", FluentValue::from("Etc/GMT-2")),
+///         ])),
+///         &mut errors),
+///     "1:30:00\u{202f}AM GMT+02:00"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// With the `tzdb` feature, daylight saving time is accounted for:
+///
+/// ```
+/// # #[cfg(feature = "tzdb")] {
+/// use fluent_datetime::{datetime, TimeZone};
+/// use icu_datetime::options::length;
+///
+/// let datetime = datetime!(1989-07-14 12:00).map_options(|o| {
+///     o.set_time_zone(TimeZone::from_iana("Europe/Paris"));
+///     o.set_time_style(Some(length::Time::Full));
+/// });
+/// assert_eq!(
+///     datetime.format(&"en-US".parse().unwrap()).unwrap(),
+///     "2:00:00\u{202f}PM Central European Summer Time"
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeZone {
+    // Shared, so datetimes are cheap to clone
+    iana_id: Arc<str>,
+    /// `None` for plain UTC offsets
+    bcp47_id: Option<TimeZoneBcp47Id>,
+    /// The offset of plain UTC offsets, in seconds
+    fixed_offset: Option<i32>,
+}
+
+impl TimeZone {
+    /// The UTC time zone
+    ///
+    /// Unlike [`from_iana`](Self::from_iana), this needs no locale data.
+    ///
+    /// ```
+    /// use fluent_datetime::TimeZone;
+    ///
+    /// assert_eq!(TimeZone::from_iana("UTC"), Some(TimeZone::utc()));
+    /// ```
+    pub fn utc() -> Self {
+        Self {
+            iana_id: "Etc/UTC".into(),
+            bcp47_id: Some("utc".parse().expect("A valid BCP-47 id")),
+            fixed_offset: None,
+        }
+    }
+
+    /// Looks up a time zone by IANA identifier
+    ///
+    /// Identifiers are matched case-insensitively and aliases are resolved
+    /// to their canonical form.  Returns `None` for unknown identifiers.
+    ///
+    /// The identifiers come from the registered data blobs if any, else
+    /// from compiled data; without either, this returns `None`.
+    pub fn from_iana(iana_id: &str) -> Option<Self> {
+        let mapper = provider::time_zone_ids(provider::default_provider()?).ok()?;
+        let (iana_id, bcp47_id) = mapper.as_borrowed().canonicalize_iana(iana_id)?;
+        Some(Self {
+            iana_id: iana_id.into(),
+            bcp47_id: Some(bcp47_id),
+            fixed_offset: None,
+        })
+    }
+
+    /// A plain UTC offset, in seconds east of UTC
+    ///
+    /// Such zones have no name; time styles that show one display the
+    /// offset, as in `GMT+02:00`.  Returns `None` beyond ±18 hours.
+    ///
+    /// ```
+    /// use fluent_datetime::TimeZone;
+    ///
+    /// let tz = TimeZone::from_offset_seconds(5 * 3600 + 1800).unwrap();
+    /// assert_eq!(tz.iana_id(), "+05:30");
+    /// assert_eq!(tz.fixed_offset(), Some(19800));
+    /// ```
+    pub fn from_offset_seconds(offset: i32) -> Option<Self> {
+        if offset.abs() > 18 * 3600 {
+            return None;
+        }
+        let sign = if offset < 0 { '-' } else { '+' };
+        let minutes = offset.abs() / 60;
+        Some(Self {
+            iana_id: format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60).into(),
+            bcp47_id: None,
+            fixed_offset: Some(offset),
+        })
+    }
+
+    /// The canonical IANA identifier of this zone, or the ISO 8601 offset
+    /// of a plain UTC offset, like `+02:00`
+    pub fn iana_id(&self) -> &str {
+        &self.iana_id
+    }
+
+    /// A plain UTC offset from a number of minutes east of UTC
+    pub(crate) fn from_offset_minutes(minutes: f64) -> Option<Self> {
+        if minutes.fract() != 0.0 || minutes.abs() > 18.0 * 60.0 {
+            return None;
+        }
+        Self::from_offset_seconds(minutes as i32 * 60)
+    }
+
+    /// The zone of a time zone argument, an IANA identifier or a UTC
+    /// offset
+    pub(crate) fn from_argument(s: &str) -> Option<Self> {
+        match Self::parse_offset(s) {
+            Some(offset) => Self::from_offset_seconds(offset),
+            None => Self::from_iana(s),
+        }
+    }
+
+    /// Parses an ISO 8601 UTC offset, as `Z`, `+02`, `+0200` or `+02:00`,
+    /// to seconds east of UTC
+    pub(crate) fn parse_offset(s: &str) -> Option<i32> {
+        if s == "Z" {
+            return Some(0);
+        }
+        let sign = match s.get(..1)? {
+            "+" => 1,
+            "-" => -1,
+            _ => return None,
+        };
+        let hhmm = s[1..].replace(':', "");
+        if !hhmm.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let (hours, minutes): (i32, i32) = match hhmm.len() {
+            2 => (hhmm.parse().ok()?, 0),
+            4 => (hhmm[..2].parse().ok()?, hhmm[2..].parse().ok()?),
+            _ => return None,
+        };
+        if minutes >= 60 {
+            return None;
+        }
+        Some(sign * (hours * 3600 + minutes * 60))
+    }
+
+    /// The offset of a plain UTC offset, in seconds east of UTC
+    ///
+    /// `None` for IANA zones, even those with a fixed offset.
+    pub fn fixed_offset(&self) -> Option<i32> {
+        self.fixed_offset
+    }
+
+    /// The offset from UTC in seconds at a given UTC datetime
+    ///
+    /// Returns `None` when the offset can't be determined.
+    pub(crate) fn offset_at(&self, utc: &DateTime<Gregorian>) -> Option<i32> {
+        self.offset_info_at(utc).map(|(offset, _)| offset)
+    }
+
+    /// The offset from UTC in seconds at a given UTC datetime, and whether
+    /// daylight saving time is in effect
+    pub(crate) fn offset_info_at(&self, utc: &DateTime<Gregorian>) -> Option<(i32, bool)> {
+        let Some(bcp47_id) = self.bcp47_id else {
+            return Some((self.fixed_offset?, false));
+        };
+        #[cfg(feature = "tzdb")]
+        if let Ok(tz) = jiff::tz::TimeZone::get(&self.iana_id) {
+            let timestamp = jiff::Timestamp::from_second(crate::convert::to_unix(utc)).ok()?;
+            let info = tz.to_offset_info(timestamp);
+            return Some((info.offset().seconds(), info.dst().is_dst()));
+        }
+        #[cfg(not(feature = "tzdb"))]
+        let _ = utc;
+        // ICU's BCP-47 identifiers encode the fixed-offset Etc zones
+        // as utcw05 (Etc/GMT+5, which is UTC-5) and utce05 (Etc/GMT-5)
+        let id = bcp47_id.0.as_str();
+        if id == "utc" || id == "gmt" {
+            return Some((0, false));
+        }
+        let sign = match id.get(..4)? {
+            "utce" => 1,
+            "utcw" => -1,
+            _ => return None,
+        };
+        let hours: i32 = id.get(4..)?.parse().ok()?;
+        Some((sign * hours * 3600, false))
+    }
+
+    /// The UTC datetime of a wall-clock datetime in this zone
+    pub(crate) fn to_utc(
+        &self,
+        local: &DateTime<Gregorian>,
+        disambiguation: Disambiguation,
+    ) -> Result<DateTime<Gregorian>, FluentDateTimeError> {
+        #[cfg(feature = "tzdb")]
+        if let (Some(_), Ok(tz)) = (self.bcp47_id, jiff::tz::TimeZone::get(&self.iana_id)) {
+            return jiff_to_utc(&tz, local, disambiguation);
+        }
+        let _ = disambiguation;
+        // Zones known without tzdb have a fixed offset
+        let unknown = || FluentDateTimeError::UnknownTimeZone(self.iana_id.to_string());
+        let offset = self.offset_at(local).ok_or_else(unknown)?;
+        crate::convert::shift_seconds(local, -i64::from(offset)).ok_or_else(unknown)
+    }
+
+    /// The wall-clock datetime in this zone of a UTC datetime
+    pub(crate) fn to_local(
+        &self,
+        utc: &DateTime<Gregorian>,
+    ) -> Result<DateTime<Gregorian>, FluentDateTimeError> {
+        let unknown = || FluentDateTimeError::UnknownTimeZone(self.iana_id.to_string());
+        let offset = self.offset_at(utc).ok_or_else(unknown)?;
+        crate::convert::shift_seconds(utc, offset.into()).ok_or_else(unknown)
+    }
+
+    /// Builds the ICU time zone for a local datetime in this zone
+    pub(crate) fn to_custom_time_zone(
+        &self,
+        metazones: &MetazoneCalculator,
+        local: &DateTime<Gregorian>,
+        offset: i32,
+        dst: bool,
+    ) -> Option<CustomTimeZone> {
+        let mut tz =
+            CustomTimeZone::new_with_offset(GmtOffset::try_from_offset_seconds(offset).ok()?);
+        // Without a zone id, ICU falls back to the GMT offset format
+        if let Some(bcp47_id) = self.bcp47_id {
+            tz.time_zone_id = Some(bcp47_id);
+            tz.metazone_id = metazones.compute_metazone_from_time_zone(bcp47_id, &local.to_iso());
+            tz.zone_variant = Some(if dst {
+                ZoneVariant::daylight()
+            } else {
+                ZoneVariant::standard()
+            });
+        }
+        Some(tz)
+    }
+}
+
+/// How to read wall-clock times that a time zone skips or repeats, when
+/// daylight saving time starts or ends
+///
+/// See [`FluentDateTime::in_time_zone`](crate::FluentDateTime::in_time_zone).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Disambiguation {
+    /// Skipped times are read with the offset before the transition, so
+    /// they move forward; repeated times take the earlier instant.  This
+    /// is what most systems, and JavaScript's Temporal, do
+    #[default]
+    Compatible,
+    /// Take the earlier of the possible instants
+    Earlier,
+    /// Take the later of the possible instants
+    Later,
+    /// Fail on skipped and repeated times
+    Reject,
+}
+
+#[cfg(feature = "tzdb")]
+fn jiff_to_utc(
+    tz: &jiff::tz::TimeZone,
+    local: &DateTime<Gregorian>,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<Gregorian>, FluentDateTimeError> {
+    use jiff::tz::AmbiguousOffset;
+
+    let iso = local.to_iso();
+    let civil = i16::try_from(iso.date.year().number)
+        .ok()
+        .and_then(|year| {
+            jiff::civil::DateTime::new(
+                year,
+                iso.date.month().ordinal as i8,
+                iso.date.day_of_month().0 as i8,
+                iso.time.hour.number() as i8,
+                iso.time.minute.number() as i8,
+                // jiff has no leap seconds
+                iso.time.second.number().min(59) as i8,
+                iso.time.nanosecond.number() as i32,
+            )
+            .ok()
+        })
+        .ok_or_else(|| FluentDateTimeError::InvalidDateTime(format!("{iso:?}")))?;
+    let ambiguous = tz.to_ambiguous_timestamp(civil);
+    let timestamp = match (disambiguation, ambiguous.offset()) {
+        (Disambiguation::Reject, AmbiguousOffset::Gap { .. }) => {
+            return Err(FluentDateTimeError::SkippedLocalTime)
+        }
+        (Disambiguation::Reject, AmbiguousOffset::Fold { .. }) => {
+            return Err(FluentDateTimeError::RepeatedLocalTime)
+        }
+        (Disambiguation::Earlier, _) => ambiguous.earlier(),
+        (Disambiguation::Later, _) => ambiguous.later(),
+        _ => ambiguous.compatible(),
+    }
+    .map_err(|_| FluentDateTimeError::InvalidDateTime(format!("{iso:?}")))?;
+    crate::convert::from_unix(timestamp.as_second(), timestamp.subsec_nanosecond() as u32)
+        .map(|utc| utc.into_inner())
+        .ok_or_else(|| FluentDateTimeError::InvalidDateTime(format!("{iso:?}")))
+}
+
+/// The time zone of the system, if it can be determined
+///
+/// For desktop and command line applications, which display local times;
+/// see [`DateTimeSupport::with_system_time_zone`](crate::DateTimeSupport::with_system_time_zone).
+///
+/// ```
+/// if let Some(tz) = fluent_datetime::system_timezone() {
+///     println!("Times are displayed in {}", tz.iana_id());
+/// }
+/// ```
+#[cfg(feature = "system-timezone")]
+pub fn system_timezone() -> Option<TimeZone> {
+    TimeZone::from_iana(&iana_time_zone::get_timezone().ok()?)
+}
@@ -0,0 +1,117 @@
+//! Checking the DATETIME calls of Fluent resources ahead of time
+
+use std::fmt;
+
+use fluent_bundle::FluentResource;
+
+use crate::{scan, FluentDateTimeError, FluentDateTimeOptions};
+
+/// A problem with a [`DATETIME`](crate::DATETIME) call, found by
+/// [`validate_resource`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// The message id, or the term id with its leading `-`
+    pub entry: String,
+    /// The attribute the call is in, if any
+    pub attribute: Option<String>,
+    /// What's wrong with the call
+    pub error: FluentDateTimeError,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.entry)?;
+        if let Some(attribute) = &self.attribute {
+            write!(f, ".{attribute}")?;
+        }
+        write!(f, ": DATETIME: {}", self.error)
+    }
+}
+
+/// Checks the [`DATETIME`](crate::DATETIME) calls of a resource
+///
+/// This finds the calls that would fail whatever the arguments, such as
+/// those with an unknown option value or time zone, so translations can
+/// be checked when they are imported.  A time zone given by a variable,
+/// as in `DATETIME($date, $tz)`, can't be checked.  Unknown options are
+/// ignored, like `DATETIME` does.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::FluentResource;
+/// use fluent_datetime::{validate_resource, FluentDateTimeError};
+///
+/// let resource = FluentResource::try_new(
+///     r#"
+/// today = {DATETIME($date, dateStyle: "fulll")}
+/// meeting = {DATETIME($date, timeStyle: "short")}
+///     .title = {DATETIME($date, "Mars/Olympus_Mons")}
+/// "#
+///     .to_string(),
+/// ).unwrap();
+/// let diagnostics = validate_resource(&resource);
+/// assert_eq!(diagnostics.len(), 2);
+/// assert_eq!(
+///     diagnostics[0].to_string(),
+///     r#"today: DATETIME: invalid value "fulll" for option dateStyle"#
+/// );
+/// assert_eq!(diagnostics[1].attribute.as_deref(), Some("title"));
+/// assert_eq!(
+///     diagnostics[1].error,
+///     FluentDateTimeError::UnknownTimeZone("Mars/Olympus_Mons".to_string())
+/// );
+/// ```
+pub fn validate_resource(resource: &FluentResource) -> Vec<Diagnostic> {
+    validate(resource, false)
+}
+
+/// Checks the [`DATETIME`](crate::DATETIME) calls of a resource, and
+/// their option names
+///
+/// Like [`validate_resource`], but options `DATETIME` doesn't know,
+/// which are usually typos, are errors too.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::FluentResource;
+/// use fluent_datetime::{validate_resource_strict, FluentDateTimeError};
+///
+/// let resource = FluentResource::try_new(
+///     "today = {DATETIME($date, datestyle: \"full\")}".to_string(),
+/// ).unwrap();
+/// assert_eq!(
+///     validate_resource_strict(&resource)[0].error,
+///     FluentDateTimeError::UnknownOption("datestyle".to_string())
+/// );
+/// ```
+pub fn validate_resource_strict(resource: &FluentResource) -> Vec<Diagnostic> {
+    validate(resource, true)
+}
+
+fn validate(resource: &FluentResource, strict: bool) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    for call in scan::datetime_calls([resource]) {
+        let location = call.location;
+        let mut errors = vec![];
+        if strict {
+            errors.extend(
+                call.unknown_options()
+                    .map(|name| FluentDateTimeError::UnknownOption(name.to_string())),
+            );
+        }
+        if call.no_datetime {
+            errors.push(FluentDateTimeError::MissingDateTime);
+        } else if let Err(e) = call.apply(FluentDateTimeOptions::default()) {
+            errors.push(e);
+        }
+        diagnostics.extend(errors.into_iter().map(|error| Diagnostic {
+            entry: if location.is_term {
+                format!("-{}", location.entry)
+            } else {
+                location.entry.to_string()
+            },
+            attribute: location.attribute.map(str::to_string),
+            error,
+        }));
+    }
+    diagnostics
+}
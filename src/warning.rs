@@ -0,0 +1,81 @@
+//! Warnings about DATETIME arguments that are ignored
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use fluent_bundle::{FluentArgs, FluentValue};
+
+use crate::{report, supported_options};
+
+/// An argument [`DATETIME`](crate::DATETIME) ignores, see
+/// [`DateTimeSupport::with_warnings`](crate::DateTimeSupport::with_warnings)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Warning {
+    /// A named option `DATETIME` doesn't know, often a typo
+    IgnoredOption(String),
+    /// Positional arguments after the datetime and time zone, counted
+    ExtraArguments(usize),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IgnoredOption(name) => write!(f, "DATETIME: ignored option {name}"),
+            Self::ExtraArguments(count) => write!(f, "DATETIME: ignored {count} extra arguments"),
+        }
+    }
+}
+
+type Sink = dyn Fn(&Warning) + Send + Sync;
+
+/// How many sent warnings are remembered before starting over
+///
+/// This bounds the memory of long-lived bundles, and forgets the
+/// addresses of patterns that were freed and may be reused.
+const SEEN_CAPACITY: usize = 1024;
+
+/// Sends warnings to a sink, once per pattern and warning
+#[derive(Clone)]
+pub(crate) struct Warnings {
+    sink: Arc<Sink>,
+    /// Pattern addresses and warnings already sent, at most
+    /// [`SEEN_CAPACITY`]
+    seen: Arc<Mutex<HashSet<(usize, Warning)>>>,
+}
+
+impl Warnings {
+    pub(crate) fn new(sink: impl Fn(&Warning) + Send + Sync + 'static) -> Self {
+        Self {
+            sink: Arc::new(sink),
+            seen: Default::default(),
+        }
+    }
+
+    /// Checks the arguments of a DATETIME call
+    pub(crate) fn check(&self, positional: &[FluentValue], named: &FluentArgs) {
+        let ignored = named
+            .iter()
+            .filter(|(name, _)| !supported_options().iter().any(|o| o.name == *name))
+            .map(|(name, _)| Warning::IgnoredOption(name.to_string()));
+        let extra = (positional.len() > 2).then(|| Warning::ExtraArguments(positional.len() - 2));
+        let pattern = report::current_pattern();
+        for warning in ignored.chain(extra) {
+            let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+            if seen.len() >= SEEN_CAPACITY {
+                seen.clear();
+            }
+            if seen.insert((pattern, warning.clone())) {
+                drop(seen);
+                (self.sink)(&warning);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Warnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Warnings").finish_non_exhaustive()
+    }
+}
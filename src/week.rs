@@ -0,0 +1,170 @@
+//! How locales lay out and number weeks
+
+use icu_calendar::types::IsoWeekday;
+use icu_calendar::week::{RelativeUnit, WeekCalculator};
+use icu_calendar::{Date, Iso};
+use icu_datetime::options::components;
+use icu_provider::DataLocale;
+
+use crate::provider::{self, load, Fallback, Provider};
+use crate::LoadError;
+
+/// The first day of the week and the weekend of a locale
+///
+/// This is the week data ICU formats dates with, so calendar widgets can
+/// be laid out to match.  It mostly depends on the region: a locale
+/// without one uses the data of its language's likely region.
+///
+/// ```
+/// use fluent_datetime::WeekInfo;
+/// use icu_calendar::types::IsoWeekday;
+///
+/// let us = WeekInfo::try_new(&"en-US".parse()?)?;
+/// assert_eq!(us.first_weekday(), IsoWeekday::Sunday);
+///
+/// let fr = WeekInfo::try_new(&"fr".parse()?)?;
+/// assert_eq!(fr.first_weekday(), IsoWeekday::Monday);
+/// assert_eq!(fr.weekend(), [IsoWeekday::Saturday, IsoWeekday::Sunday]);
+///
+/// let eg = WeekInfo::try_new(&"ar-EG".parse()?)?;
+/// assert_eq!(eg.first_weekday(), IsoWeekday::Saturday);
+/// assert!(eg.is_weekend(IsoWeekday::Friday));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WeekInfo {
+    calculator: WeekCalculator,
+}
+
+impl WeekInfo {
+    /// Loads the week data of a locale
+    ///
+    /// Locale data comes from the registered data blobs, if any, else from
+    /// compiled data, like [`FluentDateTimeFormatter::try_new`](crate::FluentDateTimeFormatter::try_new).
+    pub fn try_new(locale: &unic_langid::LanguageIdentifier) -> Result<Self, LoadError> {
+        let provider = provider::default_provider().ok_or(LoadError::NoProvider)?;
+        Self::try_new_with_provider(provider, locale)
+    }
+
+    /// Loads the week data of a locale from an [`AnyProvider`]
+    ///
+    /// [`AnyProvider`]: icu_provider::AnyProvider
+    pub fn try_new_with_any_provider(
+        provider: &(impl icu_provider::AnyProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Any(&provider), locale)
+    }
+
+    /// Loads the week data of a locale from a [`BufferProvider`]
+    ///
+    /// [`BufferProvider`]: icu_provider::BufferProvider
+    #[cfg(feature = "buffer_provider")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl icu_provider::BufferProvider + ?Sized),
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Self, LoadError> {
+        Self::try_new_with_provider(Provider::Buffer(&provider), locale)
+    }
+
+    pub(crate) fn try_new_with_provider(
+        provider: Provider,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<Self, LoadError> {
+        let langid: icu_locid::LanguageIdentifier = locale
+            .to_string()
+            .parse()
+            .map_err(|_| LoadError::InvalidLocale)?;
+        let locale = DataLocale::from(langid);
+        let fallback = Fallback::new(provider);
+        let calculator = load!(
+            fallback.get(),
+            WeekCalculator::try_new,
+            WeekCalculator::try_new_with_any_provider,
+            WeekCalculator::try_new_with_buffer_provider,
+            &locale,
+        )?;
+        Ok(Self { calculator })
+    }
+
+    /// The day weeks start on
+    pub fn first_weekday(&self) -> IsoWeekday {
+        self.calculator.first_weekday
+    }
+
+    /// The weekend days, in the order of the week
+    pub fn weekend(&self) -> Vec<IsoWeekday> {
+        self.calculator.weekend().collect()
+    }
+
+    /// Whether a day is part of the weekend
+    pub fn is_weekend(&self, weekday: IsoWeekday) -> bool {
+        self.calculator.weekend().any(|day| day == weekday)
+    }
+
+    /// The number of days of a year's first week that must fall in that
+    /// year, 1 for the week containing January 1st, 4 for ISO weeks
+    pub fn min_week_days(&self) -> u8 {
+        self.calculator.min_week_days
+    }
+}
+
+/// Formats the week of the year instead of the date
+///
+/// Weeks are numbered by the locale's rules, see [`WeekInfo`]: the first
+/// days of January can belong to the last week of the previous year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekNumber {
+    /// The number alone, like "45"
+    Numeric,
+    /// The number with the year it belongs to, like "week 45 of 1989"
+    WithYear,
+    /// The ISO 8601 week date, like "1989-W45-4", whatever the locale
+    ///
+    /// See [`FluentDateTime::to_iso_week_date`](crate::FluentDateTime::to_iso_week_date).
+    IsoWeekDate,
+}
+
+impl WeekNumber {
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "numeric" => Self::Numeric,
+            "withYear" => Self::WithYear,
+            "isoWeekDate" => Self::IsoWeekDate,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn to_components(self) -> components::Bag {
+        let mut bag = components::Bag::default();
+        bag.week = Some(components::Week::NumericWeekOfYear);
+        // ISO week dates are written without locale data, see
+        // write_localized, so any formatter will do
+        if self != Self::Numeric {
+            bag.year = Some(components::Year::NumericWeekOf);
+        }
+        bag
+    }
+}
+
+/// The ISO 8601 week date of a date, like "1989-W45-4"
+///
+/// Weeks start on Monday, and the first week of a year is the one with
+/// its first Thursday.
+pub(crate) fn iso_week_date(date: &Date<Iso>) -> String {
+    let mut iso = WeekCalculator::default();
+    iso.min_week_days = 4;
+    let week_of = date.week_of_year(&iso).expect("Valid ISO week data");
+    let year = date.year().number
+        + match week_of.unit {
+            RelativeUnit::Previous => -1,
+            RelativeUnit::Current => 0,
+            RelativeUnit::Next => 1,
+        };
+    let weekday = date.day_of_week() as u8;
+    if (0..=9999).contains(&year) {
+        format!("{year:04}-W{:02}-{weekday}", week_of.week)
+    } else {
+        format!("{year:+05}-W{:02}-{weekday}", week_of.week)
+    }
+}
@@ -0,0 +1,315 @@
+//! Weekdays on their own, like "every Thursday"
+
+use std::borrow::Cow;
+
+use fluent_bundle::types::FluentType;
+use fluent_bundle::{FluentArgs, FluentValue};
+use icu_calendar::types::IsoWeekday;
+use icu_calendar::Iso;
+use icu_locid::extensions::unicode;
+use intl_memoizer::Memoizable;
+
+use crate::provider::{self, SharedProvider};
+use crate::{as_datetime, report, DateNames, FluentDateTimeError, FluentDateTimeOptions};
+use crate::{DateTimeSupport, GimmeTheLocale, LoadError, NameContext, NameWidth};
+
+/// A day of the week, without a date, like a weekly recurrence
+///
+/// This formats as the localized standalone name of the weekday, "Thursday"
+/// by default; [`with_width`](Self::with_width) or the `weekday` option of
+/// [`WEEKDAY`](crate::WEEKDAY) pick the short or narrow name.  Names come
+/// from the same data as [`DateNames`].  When they can't be loaded, the
+/// weekday is rendered as its ISO 8601 number, from 1 for Monday to 7.
+///
+/// ```
+/// use fluent_datetime::fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use fluent_datetime::{BundleExt, FluentWeekday, IntlSupport, NameWidth};
+/// use icu_calendar::types::IsoWeekday;
+///
+/// let weekday = FluentWeekday::new(IsoWeekday::Thursday);
+/// assert_eq!(weekday.format(&"en-US".parse()?).unwrap(), "Thursday");
+/// assert_eq!(weekday.format(&"fr-FR".parse()?).unwrap(), "jeudi");
+/// let weekday = weekday.with_width(NameWidth::Short);
+/// assert_eq!(weekday.format(&"de-DE".parse()?).unwrap(), "Do");
+///
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse()?]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_intl_support(IntlSupport::new().with_weekday())?;
+/// bundle.add_resource(FluentResource::try_new(
+///     "repeat = Repeat every {$weekday}
+/// short = Every {WEEKDAY($weekday, weekday: \"short\")}
+/// number = Every {WEEKDAY($day, weekday: \"narrow\")}".to_string(),
+/// ).unwrap()).unwrap();
+/// let mut errors = vec![];
+/// let mut args = FluentArgs::new();
+/// args.set("weekday", FluentWeekday::new(IsoWeekday::Monday));
+/// args.set("day", 7);
+/// let repeat = bundle.get_message("repeat").unwrap().value().unwrap();
+/// assert_eq!(bundle.format_pattern(repeat, Some(&args), &mut errors), "Repeat every Monday");
+/// let short = bundle.get_message("short").unwrap().value().unwrap();
+/// assert_eq!(bundle.format_pattern(short, Some(&args), &mut errors), "Every Mon");
+/// let number = bundle.get_message("number").unwrap().value().unwrap();
+/// assert_eq!(bundle.format_pattern(number, Some(&args), &mut errors), "Every S");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FluentWeekday {
+    weekday: IsoWeekday,
+    width: NameWidth,
+    // For the data source and calendar of the bundle
+    options: FluentDateTimeOptions,
+}
+
+impl FluentWeekday {
+    /// A weekday, with its long name
+    pub fn new(weekday: IsoWeekday) -> Self {
+        Self {
+            weekday,
+            width: NameWidth::Long,
+            options: FluentDateTimeOptions::default(),
+        }
+    }
+
+    /// The weekday
+    pub fn weekday(&self) -> IsoWeekday {
+        self.weekday
+    }
+
+    /// How long its name is
+    pub fn width(&self) -> NameWidth {
+        self.width
+    }
+
+    /// Formats with a name of another width, in a chain
+    pub fn with_width(mut self, width: NameWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Formats the name for an explicit locale
+    ///
+    /// Fails if the locale data can't be loaded.
+    pub fn format(
+        &self,
+        locale: &unic_langid::LanguageIdentifier,
+    ) -> Result<String, FluentDateTimeError> {
+        Ok(self.try_format(locale)?)
+    }
+
+    fn try_format(&self, locale: &unic_langid::LanguageIdentifier) -> Result<String, LoadError> {
+        let names = WeekdayNames::construct(locale.clone(), self.names_args())?;
+        Ok(names.get(self.weekday).to_string())
+    }
+
+    /// What the names for this weekday depend on, besides the locale
+    fn names_args(&self) -> NamesArgs {
+        NamesArgs {
+            width: self.width,
+            calendar: self.options.calendar.clone(),
+            provider: self.options.provider.clone(),
+            generation: provider::generation(),
+        }
+    }
+
+    /// Applies the named options of a WEEKDAY call
+    fn merge_args(&mut self, args: &FluentArgs) -> Result<(), FluentDateTimeError> {
+        let mut error = None;
+        for (name, value) in args.iter() {
+            if name != "weekday" {
+                continue;
+            }
+            let width = match value {
+                FluentValue::String(s) => {
+                    NameWidth::from_keyword(s).ok_or_else(|| FluentDateTimeError::InvalidOption {
+                        name: name.to_string(),
+                        value: s.to_string(),
+                    })
+                }
+                _ => Err(FluentDateTimeError::WrongOptionType {
+                    name: name.to_string(),
+                    expected: "a string",
+                }),
+            };
+            match width {
+                Ok(width) => self.width = width,
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Renders with a formatted name or the error, falling back to the
+    /// ISO 8601 number
+    fn render(
+        &self,
+        lang: &unic_langid::LanguageIdentifier,
+        formatted: Result<String, LoadError>,
+    ) -> Cow<'static, str> {
+        match formatted {
+            Ok(s) => s.into(),
+            Err(e) => {
+                report::report(|| {
+                    format!("WEEKDAY: could not format {self:?} for locale {lang}: {e}")
+                });
+                (self.weekday as u8).to_string().into()
+            }
+        }
+    }
+}
+
+// IsoWeekday doesn't implement Hash
+impl std::hash::Hash for FluentWeekday {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.weekday as u8).hash(state);
+        self.width.hash(state);
+        self.options.hash(state);
+    }
+}
+
+impl From<IsoWeekday> for FluentWeekday {
+    fn from(weekday: IsoWeekday) -> Self {
+        Self::new(weekday)
+    }
+}
+
+impl FluentType for FluentWeekday {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let formatted = intls.with_try_get::<WeekdayNames, _, _>(self.names_args(), |names| {
+            names.get(self.weekday).to_string()
+        });
+        self.render(&lang, formatted)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        let lang = intls
+            .with_try_get::<GimmeTheLocale, _, _>((), |gimme| gimme.0.clone())
+            .expect("Infallible");
+        let formatted = intls.with_try_get::<WeekdayNames, _, _>(self.names_args(), |names| {
+            names.get(self.weekday).to_string()
+        });
+        self.render(&lang, formatted)
+    }
+}
+
+impl From<FluentWeekday> for FluentValue<'static> {
+    fn from(value: FluentWeekday) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+/// A Fluent function for weekday names
+///
+/// Register it with [`IntlSupport::with_weekday`](crate::IntlSupport::with_weekday);
+/// [`BundleExt::add_datetime_support`](crate::BundleExt::add_datetime_support)
+/// leaves it out, so translations can keep a WEEKDAY function of their own.
+///
+/// ```fluent
+/// meeting = The team meets every {WEEKDAY($weekday)}
+/// column = {WEEKDAY($weekday, weekday: "short")}
+/// ```
+///
+/// The first argument is a [`FluentWeekday`], a
+/// [`FluentDateTime`](crate::FluentDateTime), whose weekday is taken in its
+/// time zone, or an ISO 8601 weekday number, from 1 for Monday to 7.  The
+/// `weekday` option is `long` (the default), `short` or `narrow`, like in
+/// `Intl.DateTimeFormat`; see [`NameWidth`].  Invalid values are reported
+/// and the weekday is formatted without them.
+#[allow(non_snake_case)]
+pub fn WEEKDAY<'a>(positional: &[FluentValue<'a>], named: &FluentArgs) -> FluentValue<'a> {
+    weekday_with_support(positional, named, &DateTimeSupport::default())
+}
+
+pub(crate) fn weekday_with_support<'a>(
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+    support: &DateTimeSupport,
+) -> FluentValue<'a> {
+    let Some(mut weekday) = positional.first().and_then(resolve_weekday) else {
+        report::report(|| format!("WEEKDAY: not a weekday: {:?}", positional.first()));
+        return FluentValue::Error;
+    };
+    support.apply(&mut weekday.options);
+    if let Err(e) = weekday.merge_args(named) {
+        report::report(|| format!("WEEKDAY: {e}"));
+    }
+    FluentValue::Custom(Box::new(weekday))
+}
+
+fn resolve_weekday(value: &FluentValue) -> Option<FluentWeekday> {
+    match value {
+        FluentValue::Custom(cus) => {
+            if let Some(weekday) = cus.as_any().downcast_ref::<FluentWeekday>() {
+                return Some(weekday.clone());
+            }
+            let datetime = as_datetime(value)?;
+            let weekday = datetime.to_calendar(Iso).ok()?.date.day_of_week();
+            let mut weekday = FluentWeekday::new(weekday);
+            weekday.options = datetime.options.clone();
+            Some(weekday)
+        }
+        FluentValue::Number(n) if n.value.fract() == 0.0 && (1.0..=7.0).contains(&n.value) => {
+            Some(IsoWeekday::from(n.value as usize).into())
+        }
+        _ => None,
+    }
+}
+
+/// The memoizer key of [`WeekdayNames`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct NamesArgs {
+    width: NameWidth,
+    // The bundle's calendar, whose names are used
+    calendar: Option<unicode::Value>,
+    provider: Option<SharedProvider>,
+    /// See [`provider::generation`]
+    generation: u64,
+}
+
+/// The standalone weekday names of a language in one width, from Monday,
+/// memoized by bundles
+struct WeekdayNames([String; 7]);
+
+impl WeekdayNames {
+    fn get(&self, weekday: IsoWeekday) -> &str {
+        &self.0[weekday as usize - 1]
+    }
+}
+
+impl intl_memoizer::Memoizable for WeekdayNames {
+    type Args = NamesArgs;
+
+    type Error = LoadError;
+
+    fn construct(
+        lang: unic_langid::LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: std::marker::Sized,
+    {
+        let options = FluentDateTimeOptions {
+            calendar: args.calendar,
+            provider: args.provider,
+            ..FluentDateTimeOptions::default()
+        };
+        let provider = options.provider().ok_or(LoadError::NoProvider)?;
+        let names = DateNames::try_new_with_provider(provider, &lang, &options)?;
+        Ok(Self(std::array::from_fn(|i| {
+            names
+                .weekday(IsoWeekday::from(i + 1), args.width, NameContext::Standalone)
+                .to_string()
+        })))
+    }
+}